@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    DoublyLinkedList,
+    Error,
+    Operation,
+    Result,
+};
+use alloc::{
+    boxed::Box,
+    rc::Rc,
+    vec::Vec,
+};
+use core::{
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    ops::Deref,
+    result::Result as StdResult,
+};
+
+/// Determines how a `BoundedList` makes room when an insertion would exceed its budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EvictionPolicy {
+    /// Evict elements from the front (the oldest elements) until the new element fits.
+    DropOldest,
+    /// Evict the new element itself, leaving the list unchanged.
+    DropNewest,
+    /// Leave the list unchanged and fail the insertion with `Error::CapacityExceeded`.
+    Reject,
+}
+
+/// An element which `BoundedList::push()` did not insert, handed back to the caller along with the reason.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rejected<T> {
+    pub error: Error,
+    pub data: T,
+}
+
+/// Discards the rejected element, for callers which only propagate the `Error`.
+impl<T> From<Rejected<T>> for Error {
+    fn from(rejected: Rejected<T>) -> Self {
+        rejected.error
+    }
+}
+
+/// `BoundedList` wraps a `DoublyLinkedList`, constraining the total "weight" of its elements to a fixed budget.  The
+/// weight of each element is computed by a user-supplied function (each element weighs 1 for count-bounded lists).
+/// Elements are appended with `push()`; when an element does not fit, room is made according to the list's
+/// `EvictionPolicy`.  Evicted elements are returned to the caller, or passed to the eviction callback if one is set.
+pub struct BoundedList<T> {
+    list: DoublyLinkedList<T>,
+    max_weight: usize,
+    weight: usize,
+    weigh: Box<dyn Fn(&T) -> usize>,
+    policy: EvictionPolicy,
+    on_evict: Option<Box<dyn FnMut(T)>>,
+}
+
+impl<T> BoundedList<T> {
+    /// Constructor for a list holding at most `max_len` elements.
+    pub fn with_max_len(max_len: usize, policy: EvictionPolicy) -> Self {
+        Self::with_max_weight(max_len, |_| 1, policy)
+    }
+
+    /// Constructor for a list whose elements' total weight, as computed by `weigh`, is at most `max_weight`.  `weigh`
+    /// must return the same weight for an element each time it is called.
+    pub fn with_max_weight<F>(max_weight: usize, weigh: F, policy: EvictionPolicy) -> Self
+                             where F: Fn(&T) -> usize + 'static {
+        Self {
+            list: DoublyLinkedList::new(),
+            max_weight,
+            weight: 0,
+            weigh: Box::new(weigh),
+            policy,
+            on_evict: None,
+        }
+    }
+
+    /// Predicate revealing whether `data` could be inserted into an empty list without eviction.
+    pub fn can_ever_fit(&self, data: &T) -> bool {
+        (self.weigh)(data) <= self.max_weight
+    }
+
+    /// Predicate revealing whether `data` could be inserted into the list as it stands without eviction.
+    pub fn fits(&self, data: &T) -> bool {
+        self.weight
+            .checked_add((self.weigh)(data))
+            .is_some_and(|weight| weight <= self.max_weight)
+    }
+
+    /// The maximum total weight of the list's elements.
+    pub fn max_weight(&self) -> usize {
+        self.max_weight
+    }
+
+    /// The policy applied when an insertion would exceed the list's budget.
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Removes the element at the tail (the newest element) of the list and returns it.
+    pub fn pop_back(&mut self) -> Result<T> {
+        let result = self.list.pop_back();
+        self.release(&result);
+        result
+    }
+
+    /// Removes the element at the head (the oldest element) of the list and returns it.
+    pub fn pop_front(&mut self) -> Result<T> {
+        let result = self.list.pop_front();
+        self.release(&result);
+        result
+    }
+
+    /// Appends `data` to the list, first applying the list's `EvictionPolicy` if `data` does not fit within the
+    /// remaining budget.  Returns the evicted elements (oldest first), or an empty `Vec` if an eviction callback is
+    /// set.  Under `EvictionPolicy::Reject`, fails with `Error::CapacityExceeded` if `data` does not fit.  An element
+    /// which exceeds the entire budget on its own is never inserted.  Under `EvictionPolicy::DropOldest`, fails with
+    /// `Error::ExistingLiveReferences`, leaving the list unchanged, if any element which would have to be evicted has
+    /// outstanding live references.  On failure, `data` is returned in the `Rejected` error.
+    pub fn push(&mut self, data: T) -> StdResult<Vec<T>, Rejected<T>> {
+        let data_weight = (self.weigh)(&data);
+        let mut evicted = Vec::new();
+        match self.fits(&data) {
+            true => self.insert(data, data_weight),
+            false => match self.policy {
+                EvictionPolicy::Reject => {
+                    let error = Error::CapacityExceeded {
+                        op: Operation::Push,
+                        budget: self.max_weight,
+                        required: self.weight.saturating_add(data_weight),
+                    };
+                    return Err(Rejected { error, data });
+                },
+                EvictionPolicy::DropNewest => evicted.push(data),
+                EvictionPolicy::DropOldest => match self.can_ever_fit(&data) {
+                    false => evicted.push(data),
+                    true => {
+                        if let Err(error) = self.validate_evictable(data_weight) {
+                            return Err(Rejected { error, data });
+                        }
+                        while !self.fits(&data) {
+                            match self.pop_front() {
+                                Ok(victim) => evicted.push(victim),
+                                Err(error) => return Err(Rejected { error, data }),
+                            }
+                        }
+                        self.insert(data, data_weight);
+                    },
+                },
+            },
+        }
+        Ok(self.report(evicted))
+    }
+
+    /// Registers `callback` to receive every element subsequently evicted by `push()`, in place of returning them.
+    pub fn set_eviction_callback<F>(&mut self, callback: F) -> &mut Self where F: FnMut(T) + 'static {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    /// The total weight of the list's elements.
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+
+    fn insert(&mut self, data: T, data_weight: usize) {
+        self.weight = self.weight.saturating_add(data_weight);
+        self.list.push_back(data);
+    }
+
+    /// Accounts for an element leaving the list.  If the element could not be extracted (e.g. because of outstanding
+    /// live references) it has nonetheless been unlinked, so the list's weight is recomputed from its contents.
+    fn release(&mut self, result: &Result<T>) {
+        match result {
+            Ok(data) => self.weight = self.weight.saturating_sub((self.weigh)(data)),
+            Err(Error::ExistingLiveReferences { .. }) => {
+                self.weight = self.list
+                                  .iter()
+                                  .fold(0, |weight, link| weight.saturating_add((self.weigh)(&link.borrow())));
+            },
+            Err(_) => (),
+        }
+    }
+
+    /// Verifies that every element which must be evicted from the front to make room for `data_weight` can be
+    /// extracted, so that no element is unlinked unless all of them can be.
+    fn validate_evictable(&self, data_weight: usize) -> Result<()> {
+        let mut remaining = self.weight;
+        let mut victims = Vec::new();
+        let mut iter = self.list.iter();
+        while remaining.saturating_add(data_weight) > self.max_weight {
+            match iter.next() {
+                Some(link) => {
+                    remaining = remaining.saturating_sub((self.weigh)(&link.borrow()));
+                    victims.push(link);
+                },
+                None => break,
+            }
+        }
+        drop(iter);
+        // The list's own link and `victims` account for two strong references; any others are held elsewhere
+        victims.iter()
+               .enumerate()
+               .find(|(_, link)| Rc::strong_count(link) > 2)
+               .map_or(Ok(()), |(index, link)| Err(Error::ExistingLiveReferences {
+                                                       op: Operation::PopFront,
+                                                       index,
+                                                       strong: Rc::strong_count(link).saturating_sub(1),
+                                                       weak: Rc::weak_count(link),
+                                                   }))
+    }
+
+    fn report(&mut self, evicted: Vec<T>) -> Vec<T> {
+        match self.on_evict.as_mut() {
+            Some(callback) => {
+                evicted.into_iter().for_each(callback);
+                Vec::new()
+            },
+            None => evicted,
+        }
+    }
+}
+
+/// Read-only access to the underlying `DoublyLinkedList`.  Mutable access is not offered, as it would bypass the
+/// budget.
+impl<T> Deref for BoundedList<T> {
+    type Target = DoublyLinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: Debug> Debug for BoundedList<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("BoundedList")
+         .field("list", &self.list)
+         .field("max_weight", &self.max_weight)
+         .field("weight", &self.weight)
+         .field("policy", &self.policy)
+         .finish()
+    }
+}
@@ -0,0 +1,205 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+fn contents<T: Clone>(list: &BoundedList<T>) -> Vec<T> {
+    list.iter()
+        .map(|link| link.borrow().data.clone())
+        .collect()
+}
+
+#[test]
+fn push_within_budget_evicts_nothing() {
+    // given a list bounded to two elements
+    let mut sut = BoundedList::with_max_len(2, EvictionPolicy::DropOldest);
+
+    // when two elements are pushed
+    let result = (sut.push(1), sut.push(2));
+
+    // then nothing is evicted
+    assert_eq!(result, (Ok(vec![]), Ok(vec![])));
+
+    // and the list contains both elements
+    assert_eq!(contents(&sut), vec![1, 2]);
+}
+
+#[test]
+fn push_beyond_max_len_with_drop_oldest_evicts_the_head() {
+    // given a full list bounded to two elements which drops its oldest elements
+    let mut sut = BoundedList::with_max_len(2, EvictionPolicy::DropOldest);
+    sut.push(1).unwrap();
+    sut.push(2).unwrap();
+
+    // when another element is pushed
+    let result = sut.push(3);
+
+    // then the oldest element is evicted
+    assert_eq!(result, Ok(vec![1]));
+
+    // and the list contains the newest elements
+    assert_eq!(contents(&sut), vec![2, 3]);
+}
+
+#[test]
+fn push_beyond_max_len_with_drop_newest_evicts_the_new_element() {
+    // given a full list bounded to two elements which drops its newest element
+    let mut sut = BoundedList::with_max_len(2, EvictionPolicy::DropNewest);
+    sut.push(1).unwrap();
+    sut.push(2).unwrap();
+
+    // when another element is pushed
+    let result = sut.push(3);
+
+    // then the new element is evicted
+    assert_eq!(result, Ok(vec![3]));
+
+    // and the list is unchanged
+    assert_eq!(contents(&sut), vec![1, 2]);
+}
+
+#[test]
+fn push_beyond_max_len_with_reject_yields_an_error() {
+    // given a full list bounded to one element which rejects insertions beyond its budget
+    let mut sut = BoundedList::with_max_len(1, EvictionPolicy::Reject);
+    sut.push(1).unwrap();
+
+    // when another element is pushed
+    let result = sut.push(2);
+
+    // then the insertion is rejected
+    let error = Error::CapacityExceeded { op: Operation::Push, budget: 1, required: 2 };
+    assert_eq!(result, Err(Rejected { error, data: 2 }));
+
+    // and the list is unchanged
+    assert_eq!(contents(&sut), vec![1]);
+}
+
+#[test]
+fn rejected_push_hands_the_element_back() {
+    // given a full list bounded to one element which rejects insertions beyond its budget
+    let mut sut = BoundedList::with_max_len(1, EvictionPolicy::Reject);
+    sut.push(String::from("kept")).unwrap();
+
+    // when another element is pushed and rejected
+    let rejected = sut.push(String::from("returned")).unwrap_err();
+
+    // then the element is handed back to the caller
+    assert_eq!(rejected.data, "returned");
+
+    // and can be pushed once room is made for it
+    sut.pop_front().unwrap();
+    assert_eq!(sut.push(rejected.data), Ok(vec![]));
+    assert_eq!(contents(&sut), vec![String::from("returned")]);
+}
+
+#[test]
+fn push_beyond_max_weight_with_drop_oldest_evicts_until_the_new_element_fits() {
+    // given a list of strings whose total length is bounded to 10
+    let mut sut = BoundedList::with_max_weight(10, String::len, EvictionPolicy::DropOldest);
+    sut.push(String::from("abc")).unwrap();
+    sut.push(String::from("defg")).unwrap();
+    sut.push(String::from("hi")).unwrap();
+    assert_eq!(sut.weight(), 9);
+
+    // when an element weighing 6 is pushed
+    let result = sut.push(String::from("jklmno"));
+
+    // then the two oldest elements are evicted, oldest first
+    assert_eq!(result, Ok(vec![String::from("abc"), String::from("defg")]));
+
+    // and the list's weight reflects its contents
+    assert_eq!(contents(&sut), vec![String::from("hi"), String::from("jklmno")]);
+    assert_eq!(sut.weight(), 8);
+}
+
+#[test]
+fn push_of_an_element_exceeding_the_entire_budget_evicts_only_that_element() {
+    // given a non-empty list of strings whose total length is bounded to 4
+    let mut sut = BoundedList::with_max_weight(4, String::len, EvictionPolicy::DropOldest);
+    sut.push(String::from("ab")).unwrap();
+
+    // when an element which could never fit is pushed
+    let result = sut.push(String::from("abcde"));
+
+    // then only the new element is evicted
+    assert_eq!(result, Ok(vec![String::from("abcde")]));
+    assert_eq!(contents(&sut), vec![String::from("ab")]);
+}
+
+#[test]
+fn push_with_an_eviction_callback_reports_evictions_to_the_callback() {
+    // given a full list bounded to one element, with an eviction callback
+    let reported = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&reported);
+    let mut sut = BoundedList::with_max_len(1, EvictionPolicy::DropOldest);
+    sut.set_eviction_callback(move |data| sink.borrow_mut().push(data));
+    sut.push(1).unwrap();
+
+    // when another element is pushed
+    let result = sut.push(2);
+
+    // then no evicted elements are returned
+    assert_eq!(result, Ok(vec![]));
+
+    // and the evicted element is reported to the callback
+    assert_eq!(*reported.borrow(), vec![1]);
+}
+
+#[test]
+fn pop_releases_the_weight_of_the_extracted_element() {
+    // given a list of strings whose total length is bounded
+    let mut sut = BoundedList::with_max_weight(10, String::len, EvictionPolicy::Reject);
+    sut.push(String::from("abc")).unwrap();
+    sut.push(String::from("de")).unwrap();
+
+    // when elements are popped from either end
+    let result = (sut.pop_back(), sut.weight(), sut.pop_front(), sut.weight());
+
+    // then the weight of each extracted element is released
+    assert_eq!(result, (Ok(String::from("de")), 3, Ok(String::from("abc")), 0));
+}
+
+#[test]
+fn failed_pop_resynchronizes_the_weight_with_the_remaining_elements() {
+    // given a list of strings with a live reference to its head
+    let mut sut = BoundedList::with_max_weight(10, String::len, EvictionPolicy::Reject);
+    sut.push(String::from("abc")).unwrap();
+    sut.push(String::from("de")).unwrap();
+    let live_ref = sut.iter().next();
+
+    // when pop_front() is called
+    let result = sut.pop_front();
+
+    // then extraction fails
+    assert!(result.is_err());
+
+    // and the weight reflects the elements which remain in the list
+    assert_eq!(sut.weight(), 2);
+    drop(live_ref);
+}
+
+#[test]
+fn failed_eviction_leaves_the_list_unchanged() {
+    // given a list of numbers whose total is bounded to 3, which drops its oldest elements, with a live reference to
+    // its second element
+    let mut sut = BoundedList::with_max_weight(3, |data: &usize| *data, EvictionPolicy::DropOldest);
+    sut.push(1).unwrap();
+    sut.push(2).unwrap();
+    let live_ref = sut.iter().nth(1);
+
+    // when an element requiring the eviction of both existing elements is pushed
+    let result = sut.push(3);
+
+    // then the eviction fails
+    assert!(matches!(result,
+                     Err(Rejected { error: Error::ExistingLiveReferences { op: Operation::PopFront, index: 1, .. },
+                                    data: 3 })));
+
+    // and no element has been evicted
+    assert_eq!(contents(&sut), vec![1, 2]);
+    assert_eq!(sut.weight(), 3);
+    drop(live_ref);
+}
@@ -0,0 +1,86 @@
+mod ring_cursor;
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    DoublyLinkedList,
+    NodeLink,
+    Result,
+};
+pub use self::ring_cursor::RingCursor;
+use core::ops::Deref;
+
+/// `CircularList` arranges its `Node`s in a ring: moving forward from the tail arrives at the head, and moving backward
+/// from the head arrives at the tail.  The tail's `wrap` link refers back to the head, closing the ring; it is weak, so
+/// no `Rc` cycle can form, and it is kept up to date by every operation which changes the ring's ends.  The tail's
+/// `next` and the head's `prev` remain `None`, exactly as in a `DoublyLinkedList`, so `iter()` makes a single lap from
+/// head to tail; `RingCursor` follows `wrap` to go around indefinitely.
+#[derive(Debug)]
+pub struct CircularList<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> CircularList<T> {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Creates a `RingCursor` positioned at the head of the ring (or at no element, if the ring is empty).
+    pub fn cursor(&mut self) -> RingCursor<'_, T> {
+        RingCursor::new(self)
+    }
+
+    /// Removes the `Node` at the tail of the ring (immediately behind the head) and returns the `data` contained
+    /// within.
+    pub fn pop_back(&mut self) -> Result<T> {
+        self.relink(DoublyLinkedList::pop_back)
+    }
+
+    /// Removes the `Node` at the head of the ring and returns the `data` contained within.
+    pub fn pop_front(&mut self) -> Result<T> {
+        self.relink(DoublyLinkedList::pop_front)
+    }
+
+    /// Inserts a `Node` at the tail of the ring (immediately behind the head).
+    pub fn push_back(&mut self, data: T) -> &mut Self {
+        self.relink(|list| { list.push_back(data); });
+        self
+    }
+
+    /// Inserts a `Node` at the head of the ring.
+    pub fn push_front(&mut self, data: T) -> &mut Self {
+        self.relink(|list| { list.push_front(data); });
+        self
+    }
+
+    /// Applies `edit` to the underlying list, then re-points the (possibly new) tail's `wrap` link at the (possibly
+    /// new) head.  Every edit which may change either end of the ring must be made through this method.
+    fn relink<F, R>(&mut self, edit: F) -> R where F: FnOnce(&mut DoublyLinkedList<T>) -> R {
+        if let Some(tail) = self.list.tail_link() {
+            tail.borrow_mut().wrap = None;
+        }
+        let result = edit(&mut self.list);
+        if let Some(tail) = self.list.tail_link() {
+            tail.borrow_mut().wrap = self.list.head_link().as_ref().map(NodeLink::to_weak);
+        }
+        result
+    }
+}
+
+/// Idiomatic `Default` impl for types with parameterless constructors.
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read-only access to the ring as a `DoublyLinkedList` running from head to tail (e.g. `iter()` makes one lap).
+impl<T> Deref for CircularList<T> {
+    type Target = DoublyLinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    CircularList,
+    Error,
+    NodeLink,
+    Operation,
+    Result,
+    WeakLink,
+};
+use alloc::rc::Rc;
+
+/// `RingCursor` designates one element of a `CircularList` (its "current" element) and moves around the ring
+/// indefinitely in either direction.  The current element can be removed, after which the cursor continues from its
+/// successor, making the cursor suitable for round-robin scheduling and elimination games.
+#[derive(Debug)]
+pub struct RingCursor<'a, T> {
+    ring: &'a mut CircularList<T>,
+    current: Option<NodeLink<T>>,
+}
+
+impl<'a, T> RingCursor<'a, T> {
+    pub(super) fn new(ring: &'a mut CircularList<T>) -> Self {
+        let current = ring.list.head_link();
+        Self {
+            ring,
+            current,
+        }
+    }
+
+    /// Returns a `NodeLink` to the current element, or `None` if the ring is empty.  Note: the current element cannot
+    /// be removed while the returned `NodeLink` is held.
+    pub fn current(&self) -> Option<NodeLink<T>> {
+        self.current.clone()
+    }
+
+    /// Inserts `data` into the ring immediately after the current element, or as the ring's only element if the ring
+    /// is empty (in which case it becomes the current element).
+    pub fn insert_after(&mut self, data: T) -> &mut Self {
+        match self.current.clone() {
+            Some(current) => { self.ring.relink(|list| list.insert_after_link(current, data)); },
+            None => self.insert_into_empty(data),
+        }
+        self
+    }
+
+    /// Inserts `data` into the ring immediately before the current element, or as the ring's only element if the ring
+    /// is empty (in which case it becomes the current element).
+    pub fn insert_before(&mut self, data: T) -> &mut Self {
+        match self.current.clone() {
+            Some(current) => { self.ring.relink(|list| list.insert_before_link(current, data)); },
+            None => self.insert_into_empty(data),
+        }
+        self
+    }
+
+    /// Advances the cursor to the current element's successor, wrapping from the tail to the head.
+    pub fn move_next(&mut self) -> &mut Self {
+        self.current = self.current
+                           .take()
+                           .and_then(|current| self.successor(&current));
+        self
+    }
+
+    /// Moves the cursor to the current element's predecessor, wrapping from the head to the tail.
+    pub fn move_prev(&mut self) -> &mut Self {
+        self.current = self.current
+                           .take()
+                           .and_then(|current| self.predecessor(&current));
+        self
+    }
+
+    /// Removes the current element from the ring and returns it, leaving the cursor at the removed element's successor
+    /// (or at no element if the ring is now empty).  On error, the ring and the cursor are unchanged.
+    pub fn remove_current(&mut self) -> Result<T> {
+        let current = self.current
+                          .take()
+                          .ok_or(Error::EmptyList { op: Operation::RemoveCurrent })?;
+        let prev = current.borrow().prev.as_ref().and_then(WeakLink::to_strong);
+        // The successor of the ring's only element is the element itself, which will not survive removal
+        let next = self.successor(&current).filter(|next| !Rc::ptr_eq(next, &current));
+        match self.ring.relink(|list| list.remove(current)) {
+            Ok(data) => {
+                self.current = next;
+                Ok(data)
+            },
+            Err(err) => {
+                // The element remains in the ring; recover the cursor's position from the element's predecessor
+                self.current = match prev {
+                    Some(prev) => prev.borrow().next.clone(),
+                    None => self.ring.list.head_link(),
+                };
+                Err(err)
+            },
+        }
+    }
+
+    fn insert_into_empty(&mut self, data: T) {
+        self.ring.push_back(data);
+        self.current = self.ring.list.head_link();
+    }
+
+    fn predecessor(&self, node: &NodeLink<T>) -> Option<NodeLink<T>> {
+        node.borrow()
+            .prev
+            .as_ref()
+            .and_then(WeakLink::to_strong)
+            .or_else(|| self.ring.list.tail_link())
+    }
+
+    fn successor(&self, node: &NodeLink<T>) -> Option<NodeLink<T>> {
+        let node = node.borrow();
+        node.next
+            .clone()
+            .or_else(|| node.wrap.as_ref().and_then(WeakLink::to_strong))
+    }
+}
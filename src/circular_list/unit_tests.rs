@@ -0,0 +1,216 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::{
+    Error,
+    NodeLink,
+    Operation,
+    WeakLink,
+};
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+fn ring_of<T: Clone>(data: &[T]) -> CircularList<T> {
+    let mut ring = CircularList::new();
+    data.iter().cloned().for_each(|datum| { ring.push_back(datum); });
+    ring
+}
+
+fn current<T: Clone>(cursor: &RingCursor<T>) -> Option<T> {
+    cursor.current().map(|link| link.borrow().data.clone())
+}
+
+/// Counts its own drops, to detect leaked `Node`s.
+struct DropCounter(Rc<Cell<usize>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get().saturating_add(1));
+    }
+}
+
+#[test]
+fn move_next_wraps_from_the_tail_to_the_head_indefinitely() {
+    // given a ring of three elements and a cursor at its head
+    let mut ring = ring_of(&[1, 2, 3]);
+    let mut sut = ring.cursor();
+
+    // when the cursor is advanced repeatedly
+    let result = (0..7).map(|_| {
+                           let datum = current(&sut).unwrap();
+                           sut.move_next();
+                           datum
+                       })
+                       .collect::<Vec<_>>();
+
+    // then it visits the elements in order, wrapping around
+    assert_eq!(result, vec![1, 2, 3, 1, 2, 3, 1]);
+}
+
+#[test]
+fn move_prev_wraps_from_the_head_to_the_tail() {
+    // given a ring of three elements and a cursor at its head
+    let mut ring = ring_of(&[1, 2, 3]);
+    let mut sut = ring.cursor();
+
+    // when the cursor is moved backward
+    sut.move_prev();
+
+    // then it arrives at the tail
+    assert_eq!(current(&sut), Some(3));
+
+    // and continues backward around the ring
+    sut.move_prev();
+    assert_eq!(current(&sut), Some(2));
+}
+
+#[test]
+fn remove_current_removes_elements_and_keeps_going() {
+    // given a ring of seven elements (the Josephus problem with a step of 3)
+    let mut ring = ring_of(&[1, 2, 3, 4, 5, 6, 7]);
+    let mut sut = ring.cursor();
+
+    // when every third element is removed until the ring is empty
+    let mut result = Vec::new();
+    while sut.current().is_some() {
+        sut.move_next().move_next();
+        result.push(sut.remove_current().unwrap());
+    }
+
+    // then elements are eliminated in the expected order
+    assert_eq!(result, vec![3, 6, 2, 7, 5, 1, 4]);
+
+    // and the ring is empty
+    assert_eq!(sut.remove_current(), Err(Error::EmptyList { op: Operation::RemoveCurrent }));
+    drop(sut);
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn remove_current_of_the_tail_leaves_the_cursor_at_the_head() {
+    // given a ring of three elements and a cursor at its tail
+    let mut ring = ring_of(&[1, 2, 3]);
+    let mut sut = ring.cursor();
+    sut.move_prev();
+
+    // when the current element is removed
+    let result = sut.remove_current();
+
+    // then the tail is returned
+    assert_eq!(result, Ok(3));
+
+    // and the cursor wraps to the head
+    assert_eq!(current(&sut), Some(1));
+}
+
+#[test]
+fn remove_current_with_a_live_reference_leaves_the_ring_and_cursor_unchanged() {
+    // given a ring and a cursor at its middle element, with a live reference to that element
+    let mut ring = ring_of(&[1, 2, 3]);
+    let mut sut = ring.cursor();
+    sut.move_next();
+    let live_ref = sut.current();
+
+    // when the current element is removed
+    let result = sut.remove_current();
+
+    // then the removal fails
+    assert!(result.is_err());
+
+    // and the cursor remains at the same element
+    assert!(Rc::ptr_eq(&sut.current().unwrap(), &live_ref.unwrap()));
+    drop(sut);
+    assert_eq!(ring.len(), 3);
+}
+
+#[test]
+fn insert_into_an_empty_ring_positions_the_cursor_at_the_new_element() {
+    // given an empty ring and a cursor over it
+    let mut ring = CircularList::new();
+    let mut sut = ring.cursor();
+
+    // when data is inserted after the (absent) current element, and then around it
+    sut.insert_after(2).insert_after(3).insert_before(1);
+
+    // then the cursor is at the first element inserted
+    assert_eq!(current(&sut), Some(2));
+
+    // and the ring holds the elements in the expected order
+    drop(sut);
+    assert_eq!(ring.iter().map(|link| link.borrow().data).collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+/// Returns the data of the `Node` referenced by the tail's `wrap` link, and the data of every other `Node` which has
+/// a `wrap` link.
+fn wraps<T: Clone>(ring: &CircularList<T>) -> (Option<T>, Vec<T>) {
+    let tail = ring.tail_link();
+    let wrapped = |link: &NodeLink<T>| link.borrow().wrap.as_ref().and_then(WeakLink::to_strong);
+    let strays = ring.iter()
+                     .filter(|link| tail.as_ref().is_none_or(|tail| !Rc::ptr_eq(link, tail)))
+                     .filter_map(|link| wrapped(&link).map(|_| link.borrow().data.clone()))
+                     .collect();
+    (tail.and_then(|tail| wrapped(&tail)).map(|head| head.borrow().data.clone()), strays)
+}
+
+#[test]
+fn the_tail_links_back_to_the_head_as_the_ring_changes() {
+    // given a ring of two elements
+    let mut ring = ring_of(&[2, 3]);
+    assert_eq!(wraps(&ring), (Some(2), vec![]));
+
+    // when elements are pushed and popped at either end, then only the tail links back to the head
+    ring.push_front(1);
+    assert_eq!(wraps(&ring), (Some(1), vec![]));
+    ring.push_back(4);
+    assert_eq!(wraps(&ring), (Some(1), vec![]));
+    ring.pop_front().unwrap();
+    assert_eq!(wraps(&ring), (Some(2), vec![]));
+    ring.pop_back().unwrap();
+    assert_eq!(wraps(&ring), (Some(2), vec![]));
+
+    // and when elements are inserted and removed through a cursor at the tail, the same holds
+    let mut cursor = ring.cursor();
+    cursor.move_prev().insert_after(5);
+    drop(cursor);
+    assert_eq!(wraps(&ring), (Some(2), vec![]));
+    let mut cursor = ring.cursor();
+    cursor.remove_current().unwrap();
+    drop(cursor);
+    assert_eq!(wraps(&ring), (Some(3), vec![]));
+
+    // and a ring of one element links its only element to itself
+    ring.pop_back().unwrap();
+    assert_eq!(wraps(&ring), (Some(3), vec![]));
+}
+
+#[test]
+fn a_popped_element_no_longer_links_back_to_the_head() {
+    // given a ring of two elements and a live reference to its tail
+    let mut ring = ring_of(&[1, 2]);
+    let tail = ring.tail_link().unwrap();
+
+    // when the tail is popped (failing, as it is referenced, but unlinking it nonetheless)
+    let _ = ring.pop_back();
+
+    // then the former tail no longer links back to the head
+    assert!(tail.borrow().wrap.is_none());
+}
+
+#[test]
+fn dropping_a_ring_frees_every_node() {
+    // given a ring of elements which count their drops, traversed past its wrap point
+    let drops = Rc::new(Cell::new(0));
+    let mut ring = CircularList::new();
+    (0..4).for_each(|_| { ring.push_back(DropCounter(Rc::clone(&drops))); });
+    {
+        let mut cursor = ring.cursor();
+        (0..10).for_each(|_| { cursor.move_next(); });
+    }
+
+    // when the ring is dropped
+    drop(ring);
+
+    // then every element is dropped (i.e. the ring does not leak through an `Rc` cycle)
+    assert_eq!(drops.get(), 4);
+}
@@ -1,2 +1,79 @@
+mod de_de;
 mod en_us;
+mod fr_fr;
+mod ja_jp;
+#[cfg(test)]
+mod unit_tests;
 pub use self::en_us::*;
+use crate::Locale;
+
+/// Declares `MsgKey` with the given variants, along with (under test) `MsgKey::ALL` listing every one of them, so that
+/// the two cannot drift apart.
+macro_rules! msg_keys {
+    ($($key:ident),+ $(,)?) => {
+        /// Identifies a user-facing message independently of the language it is rendered in.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum MsgKey {
+            $($key),+
+        }
+
+        #[cfg(test)]
+        impl MsgKey {
+            /// Every key a complete message catalog is expected to define.
+            pub const ALL: &'static [Self] = &[$(MsgKey::$key),+];
+        }
+    };
+}
+
+msg_keys! {
+    ErrCapacityExceeded,
+    ErrConcurrentModification,
+    ErrEmptyList,
+    ErrExistingLiveReferences1,
+    ErrExistingLiveReferences2,
+    ErrIndexOutOfBounds,
+    ErrInternalBoundaryInsertion,
+    ErrInternalInvalidIndex,
+    ErrInternalNodeNotInList,
+    ErrInternalUnexpectedLiveReferences,
+    ErrInternalWeakUpgradeRace,
+    ErrNodeNotInList,
+    ErrNodeNotReachable,
+    CtxBudget,
+    CtxIndex,
+    CtxLen,
+    CtxOperation,
+    CtxRequired,
+    CtxWeakReferences,
+}
+
+/// A message catalog maps each `MsgKey` to its rendering in one language.  Catalogs may be incomplete; missing keys
+/// fall back to `en_us`.
+pub type Catalog = &'static [(MsgKey, &'static str)];
+
+/// Returns the message catalog for `locale`.
+pub fn catalog(locale: Locale) -> Catalog {
+    match locale {
+        Locale::DeDe => de_de::CATALOG,
+        Locale::EnUs => en_us::CATALOG,
+        Locale::FrFr => fr_fr::CATALOG,
+        Locale::JaJp => ja_jp::CATALOG,
+    }
+}
+
+/// Returns the message for `key` in `locale`, falling back to `en_us` if `locale`'s catalog does not define `key`.
+pub fn lookup(locale: Locale, key: MsgKey) -> &'static str {
+    lookup_in(catalog(locale), key)
+}
+
+fn find(catalog: Catalog, key: MsgKey) -> Option<&'static str> {
+    catalog.iter()
+           .find(|(k, _)| *k == key)
+           .map(|(_, msg)| *msg)
+}
+
+fn lookup_in(catalog: Catalog, key: MsgKey) -> &'static str {
+    find(catalog, key).or_else(|| find(en_us::CATALOG, key))
+                      // `en_us` is verified complete by unit tests, so this default is never observed
+                      .unwrap_or_default()
+}
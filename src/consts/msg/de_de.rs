@@ -0,0 +1,46 @@
+use super::MsgKey;
+
+pub const ERR_CAPACITY_EXCEEDED: &str = "Fehler: Kapazität der beschränkten Liste überschritten";
+pub const ERR_CONCURRENT_MODIFICATION: &str = "Fehler: Die Liste wurde während der Iteration strukturell verändert";
+pub const ERR_EMPTY_LIST: &str = "Fehler: Die doppelt verkettete Liste ist leer";
+pub const ERR_EXISTING_LIVE_REFERENCES_1: &str = "Fehler: Auf den angegebenen `Node` zeigen";
+pub const ERR_EXISTING_LIVE_REFERENCES_2: &str = "aktive Referenzen; Vorgang fehlgeschlagen";
+pub const ERR_INDEX_OUT_OF_BOUNDS: &str = "Fehler: Index außerhalb des gültigen Bereichs";
+pub const ERR_INTERNAL_BOUNDARY_INSERTION: &str = "Interner Fehler: Einfügen an einem Ende der Liste \
+                                                   lag außerhalb des gültigen Bereichs";
+pub const ERR_INTERNAL_INVALID_INDEX: &str = "Interner Fehler: Index verweist auf kein belegtes Element";
+pub const ERR_INTERNAL_NODE_NOT_IN_LIST: &str = "Interner Fehler: Ein indizierter `Node` gehört nicht zu seiner Liste";
+pub const ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES: &str = "Interner Fehler: Auf einen nur von seiner Sammlung \
+                                                           gehaltenen `Node` zeigen aktive Referenzen";
+pub const ERR_INTERNAL_WEAK_UPGRADE_RACE: &str = "Interner Fehler: Upgrade der `Weak`-Referenz fehlgeschlagen; \
+                                                  Race Condition erkannt";
+pub const ERR_NODE_NOT_IN_LIST: &str = "Fehler: Der angegebene `Node` gehört nicht zu dieser Liste";
+pub const ERR_NODE_NOT_REACHABLE: &str = "Fehler: Der End-`Node` ist vom Start-`Node` aus nicht erreichbar";
+pub const CTX_BUDGET: &str = "Budget";
+pub const CTX_INDEX: &str = "Index";
+pub const CTX_LEN: &str = "Länge";
+pub const CTX_OPERATION: &str = "Vorgang";
+pub const CTX_REQUIRED: &str = "benötigt";
+pub const CTX_WEAK_REFERENCES: &str = "schwache Referenzen";
+
+pub const CATALOG: &[(MsgKey, &str)] = &[
+    (MsgKey::ErrCapacityExceeded, ERR_CAPACITY_EXCEEDED),
+    (MsgKey::ErrConcurrentModification, ERR_CONCURRENT_MODIFICATION),
+    (MsgKey::ErrEmptyList, ERR_EMPTY_LIST),
+    (MsgKey::ErrExistingLiveReferences1, ERR_EXISTING_LIVE_REFERENCES_1),
+    (MsgKey::ErrExistingLiveReferences2, ERR_EXISTING_LIVE_REFERENCES_2),
+    (MsgKey::ErrIndexOutOfBounds, ERR_INDEX_OUT_OF_BOUNDS),
+    (MsgKey::ErrInternalBoundaryInsertion, ERR_INTERNAL_BOUNDARY_INSERTION),
+    (MsgKey::ErrInternalInvalidIndex, ERR_INTERNAL_INVALID_INDEX),
+    (MsgKey::ErrInternalNodeNotInList, ERR_INTERNAL_NODE_NOT_IN_LIST),
+    (MsgKey::ErrInternalUnexpectedLiveReferences, ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES),
+    (MsgKey::ErrInternalWeakUpgradeRace, ERR_INTERNAL_WEAK_UPGRADE_RACE),
+    (MsgKey::ErrNodeNotInList, ERR_NODE_NOT_IN_LIST),
+    (MsgKey::ErrNodeNotReachable, ERR_NODE_NOT_REACHABLE),
+    (MsgKey::CtxBudget, CTX_BUDGET),
+    (MsgKey::CtxIndex, CTX_INDEX),
+    (MsgKey::CtxLen, CTX_LEN),
+    (MsgKey::CtxOperation, CTX_OPERATION),
+    (MsgKey::CtxRequired, CTX_REQUIRED),
+    (MsgKey::CtxWeakReferences, CTX_WEAK_REFERENCES),
+];
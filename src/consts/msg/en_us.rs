@@ -1,4 +1,45 @@
+use super::MsgKey;
+
+pub const ERR_CAPACITY_EXCEEDED: &str = "Error: Capacity of bounded list exceeded";
+pub const ERR_CONCURRENT_MODIFICATION: &str = "Error: The list was structurally modified during iteration";
 pub const ERR_EMPTY_LIST: &str = "Error: Doubly Linked List is empty";
 pub const ERR_EXISTING_LIVE_REFERENCES_1: &str = "Error: The specified `Node` has";
 pub const ERR_EXISTING_LIVE_REFERENCES_2: &str = "live references pointing to it; operation failed";
+pub const ERR_INDEX_OUT_OF_BOUNDS: &str = "Error: Index out of bounds";
+pub const ERR_INTERNAL_BOUNDARY_INSERTION: &str = "Internal error: insertion at either end of the list \
+                                                   was out of bounds";
+pub const ERR_INTERNAL_INVALID_INDEX: &str = "Internal error: index does not refer to an allocated element";
+pub const ERR_INTERNAL_NODE_NOT_IN_LIST: &str = "Internal error: an indexed `Node` does not belong to its list";
+pub const ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES: &str = "Internal error: a `Node` held only by its collection has \
+                                                           live references";
 pub const ERR_INTERNAL_WEAK_UPGRADE_RACE: &str = "Internal error: upgrade of `Weak` reference failed; Race condition detected";
+pub const ERR_NODE_NOT_IN_LIST: &str = "Error: The specified `Node` does not belong to this list";
+pub const ERR_NODE_NOT_REACHABLE: &str = "Error: The end `Node` is not reachable from the start `Node`";
+pub const CTX_BUDGET: &str = "budget";
+pub const CTX_INDEX: &str = "index";
+pub const CTX_LEN: &str = "length";
+pub const CTX_OPERATION: &str = "operation";
+pub const CTX_REQUIRED: &str = "required";
+pub const CTX_WEAK_REFERENCES: &str = "weak references";
+
+pub const CATALOG: &[(MsgKey, &str)] = &[
+    (MsgKey::ErrCapacityExceeded, ERR_CAPACITY_EXCEEDED),
+    (MsgKey::ErrConcurrentModification, ERR_CONCURRENT_MODIFICATION),
+    (MsgKey::ErrEmptyList, ERR_EMPTY_LIST),
+    (MsgKey::ErrExistingLiveReferences1, ERR_EXISTING_LIVE_REFERENCES_1),
+    (MsgKey::ErrExistingLiveReferences2, ERR_EXISTING_LIVE_REFERENCES_2),
+    (MsgKey::ErrIndexOutOfBounds, ERR_INDEX_OUT_OF_BOUNDS),
+    (MsgKey::ErrInternalBoundaryInsertion, ERR_INTERNAL_BOUNDARY_INSERTION),
+    (MsgKey::ErrInternalInvalidIndex, ERR_INTERNAL_INVALID_INDEX),
+    (MsgKey::ErrInternalNodeNotInList, ERR_INTERNAL_NODE_NOT_IN_LIST),
+    (MsgKey::ErrInternalUnexpectedLiveReferences, ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES),
+    (MsgKey::ErrInternalWeakUpgradeRace, ERR_INTERNAL_WEAK_UPGRADE_RACE),
+    (MsgKey::ErrNodeNotInList, ERR_NODE_NOT_IN_LIST),
+    (MsgKey::ErrNodeNotReachable, ERR_NODE_NOT_REACHABLE),
+    (MsgKey::CtxBudget, CTX_BUDGET),
+    (MsgKey::CtxIndex, CTX_INDEX),
+    (MsgKey::CtxLen, CTX_LEN),
+    (MsgKey::CtxOperation, CTX_OPERATION),
+    (MsgKey::CtxRequired, CTX_REQUIRED),
+    (MsgKey::CtxWeakReferences, CTX_WEAK_REFERENCES),
+];
@@ -0,0 +1,47 @@
+use super::MsgKey;
+
+pub const ERR_CAPACITY_EXCEEDED: &str = "Erreur : capacité de la liste bornée dépassée";
+pub const ERR_CONCURRENT_MODIFICATION: &str = "Erreur : la liste a été modifiée structurellement \
+                                               pendant l'itération";
+pub const ERR_EMPTY_LIST: &str = "Erreur : la liste doublement chaînée est vide";
+pub const ERR_EXISTING_LIVE_REFERENCES_1: &str = "Erreur : le `Node` spécifié a";
+pub const ERR_EXISTING_LIVE_REFERENCES_2: &str = "références actives pointant vers lui ; l'opération a échoué";
+pub const ERR_INDEX_OUT_OF_BOUNDS: &str = "Erreur : indice hors limites";
+pub const ERR_INTERNAL_BOUNDARY_INSERTION: &str = "Erreur interne : insertion à une extrémité de la liste hors limites";
+pub const ERR_INTERNAL_INVALID_INDEX: &str = "Erreur interne : l'indice ne désigne aucun élément alloué";
+pub const ERR_INTERNAL_NODE_NOT_IN_LIST: &str = "Erreur interne : un `Node` indexé n'appartient pas à sa liste";
+pub const ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES: &str = "Erreur interne : un `Node` détenu par sa seule collection \
+                                                           a des références actives";
+pub const ERR_INTERNAL_WEAK_UPGRADE_RACE: &str = "Erreur interne : la promotion de la référence `Weak` a échoué ; \
+                                                  situation de compétition détectée";
+pub const ERR_NODE_NOT_IN_LIST: &str = "Erreur : le `Node` spécifié n'appartient pas à cette liste";
+pub const ERR_NODE_NOT_REACHABLE: &str = "Erreur : le `Node` de fin n'est pas atteignable \
+                                          depuis le `Node` de début";
+pub const CTX_BUDGET: &str = "budget";
+pub const CTX_INDEX: &str = "indice";
+pub const CTX_LEN: &str = "longueur";
+pub const CTX_OPERATION: &str = "opération";
+pub const CTX_REQUIRED: &str = "requis";
+pub const CTX_WEAK_REFERENCES: &str = "références faibles";
+
+pub const CATALOG: &[(MsgKey, &str)] = &[
+    (MsgKey::ErrCapacityExceeded, ERR_CAPACITY_EXCEEDED),
+    (MsgKey::ErrConcurrentModification, ERR_CONCURRENT_MODIFICATION),
+    (MsgKey::ErrEmptyList, ERR_EMPTY_LIST),
+    (MsgKey::ErrExistingLiveReferences1, ERR_EXISTING_LIVE_REFERENCES_1),
+    (MsgKey::ErrExistingLiveReferences2, ERR_EXISTING_LIVE_REFERENCES_2),
+    (MsgKey::ErrIndexOutOfBounds, ERR_INDEX_OUT_OF_BOUNDS),
+    (MsgKey::ErrInternalBoundaryInsertion, ERR_INTERNAL_BOUNDARY_INSERTION),
+    (MsgKey::ErrInternalInvalidIndex, ERR_INTERNAL_INVALID_INDEX),
+    (MsgKey::ErrInternalNodeNotInList, ERR_INTERNAL_NODE_NOT_IN_LIST),
+    (MsgKey::ErrInternalUnexpectedLiveReferences, ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES),
+    (MsgKey::ErrInternalWeakUpgradeRace, ERR_INTERNAL_WEAK_UPGRADE_RACE),
+    (MsgKey::ErrNodeNotInList, ERR_NODE_NOT_IN_LIST),
+    (MsgKey::ErrNodeNotReachable, ERR_NODE_NOT_REACHABLE),
+    (MsgKey::CtxBudget, CTX_BUDGET),
+    (MsgKey::CtxIndex, CTX_INDEX),
+    (MsgKey::CtxLen, CTX_LEN),
+    (MsgKey::CtxOperation, CTX_OPERATION),
+    (MsgKey::CtxRequired, CTX_REQUIRED),
+    (MsgKey::CtxWeakReferences, CTX_WEAK_REFERENCES),
+];
@@ -0,0 +1,44 @@
+use super::MsgKey;
+
+pub const ERR_CAPACITY_EXCEEDED: &str = "エラー: 上限付きリストの容量を超えました";
+pub const ERR_CONCURRENT_MODIFICATION: &str = "エラー: 反復処理中にリストの構造が変更されました";
+pub const ERR_EMPTY_LIST: &str = "エラー: 双方向連結リストが空です";
+pub const ERR_EXISTING_LIVE_REFERENCES_1: &str = "エラー: 指定された `Node` には";
+pub const ERR_EXISTING_LIVE_REFERENCES_2: &str = "個の有効な参照が存在します。操作は失敗しました";
+pub const ERR_INDEX_OUT_OF_BOUNDS: &str = "エラー: インデックスが範囲外です";
+pub const ERR_INTERNAL_BOUNDARY_INSERTION: &str = "内部エラー: リストの端への挿入が範囲外でした";
+pub const ERR_INTERNAL_INVALID_INDEX: &str = "内部エラー: インデックスが割り当て済みの要素を指していません";
+pub const ERR_INTERNAL_NODE_NOT_IN_LIST: &str = "内部エラー: 索引付けされた `Node` がそのリストに属していません";
+pub const ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES: &str = "内部エラー: コレクションのみが保持する `Node` に有効な参照が存在します";
+pub const ERR_INTERNAL_WEAK_UPGRADE_RACE: &str = "内部エラー: `Weak` 参照のアップグレードに失敗しました。\
+                                                  競合状態が検出されました";
+pub const ERR_NODE_NOT_IN_LIST: &str = "エラー: 指定された `Node` はこのリストに属していません";
+pub const ERR_NODE_NOT_REACHABLE: &str = "エラー: 終了 `Node` は開始 `Node` から到達できません";
+pub const CTX_BUDGET: &str = "上限";
+pub const CTX_INDEX: &str = "インデックス";
+pub const CTX_LEN: &str = "長さ";
+pub const CTX_OPERATION: &str = "操作";
+pub const CTX_REQUIRED: &str = "必要量";
+pub const CTX_WEAK_REFERENCES: &str = "弱参照";
+
+pub const CATALOG: &[(MsgKey, &str)] = &[
+    (MsgKey::ErrCapacityExceeded, ERR_CAPACITY_EXCEEDED),
+    (MsgKey::ErrConcurrentModification, ERR_CONCURRENT_MODIFICATION),
+    (MsgKey::ErrEmptyList, ERR_EMPTY_LIST),
+    (MsgKey::ErrExistingLiveReferences1, ERR_EXISTING_LIVE_REFERENCES_1),
+    (MsgKey::ErrExistingLiveReferences2, ERR_EXISTING_LIVE_REFERENCES_2),
+    (MsgKey::ErrIndexOutOfBounds, ERR_INDEX_OUT_OF_BOUNDS),
+    (MsgKey::ErrInternalBoundaryInsertion, ERR_INTERNAL_BOUNDARY_INSERTION),
+    (MsgKey::ErrInternalInvalidIndex, ERR_INTERNAL_INVALID_INDEX),
+    (MsgKey::ErrInternalNodeNotInList, ERR_INTERNAL_NODE_NOT_IN_LIST),
+    (MsgKey::ErrInternalUnexpectedLiveReferences, ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES),
+    (MsgKey::ErrInternalWeakUpgradeRace, ERR_INTERNAL_WEAK_UPGRADE_RACE),
+    (MsgKey::ErrNodeNotInList, ERR_NODE_NOT_IN_LIST),
+    (MsgKey::ErrNodeNotReachable, ERR_NODE_NOT_REACHABLE),
+    (MsgKey::CtxBudget, CTX_BUDGET),
+    (MsgKey::CtxIndex, CTX_INDEX),
+    (MsgKey::CtxLen, CTX_LEN),
+    (MsgKey::CtxOperation, CTX_OPERATION),
+    (MsgKey::CtxRequired, CTX_REQUIRED),
+    (MsgKey::CtxWeakReferences, CTX_WEAK_REFERENCES),
+];
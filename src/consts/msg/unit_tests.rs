@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn every_catalog_defines_every_message_key() {
+    // given every supported locale
+    for &locale in Locale::ALL {
+        // when its catalog is consulted for every message key
+        let sut = catalog(locale);
+
+        for &key in MsgKey::ALL {
+            // then the catalog defines the key
+            assert!(find(sut, key).is_some(), "{:?} catalog is missing {:?}", locale, key);
+        }
+    }
+}
+
+#[test]
+fn catalogs_define_each_message_key_only_once() {
+    // given every supported locale
+    for &locale in Locale::ALL {
+        // when its catalog is inspected
+        let sut = catalog(locale);
+
+        // then no key is defined more than once
+        for &key in MsgKey::ALL {
+            assert!(sut.iter().filter(|(k, _)| *k == key).count() <= 1,
+                    "{:?} catalog defines {:?} more than once", locale, key);
+        }
+    }
+}
+
+#[test]
+fn lookup_of_a_key_missing_from_a_catalog_falls_back_to_en_us() {
+    // given a catalog which does not define a key
+    let partial_catalog: Catalog = &[(MsgKey::ErrEmptyList, "partial")];
+
+    // when the missing key is looked up
+    let result = lookup_in(partial_catalog, MsgKey::ErrInternalWeakUpgradeRace);
+
+    // then the `en_us` message is returned
+    assert_eq!(result, ERR_INTERNAL_WEAK_UPGRADE_RACE);
+
+    // and a key which is defined is returned from the catalog itself
+    assert_eq!(lookup_in(partial_catalog, MsgKey::ErrEmptyList), "partial");
+}
@@ -0,0 +1,338 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    Error,
+    Operation,
+    Result,
+};
+use alloc::vec::Vec;
+
+/// The root header, through which the uncovered primary columns are linked.
+const ROOT: usize = 0;
+
+/// The links of a node of the matrix.  Like a list `Node`'s `next` and `prev`, a node's links remember its neighbours
+/// after it is unlinked from them, so it can be relinked in O(1).
+#[derive(Clone, Copy, Debug)]
+struct Links {
+    left: usize,
+    right: usize,
+    up: usize,
+    down: usize,
+    column: usize,
+    /// The row to which the node belongs (meaningless for headers).
+    row: usize,
+}
+
+/// `Dlx` is a sparse 0/1 matrix in Knuth's "Dancing Links" representation, for solving exact-cover problems with
+/// Algorithm X.  Every 1 is a node linked four ways, into a circular list of its row and a circular list of its column,
+/// so the matrix forms a torus.  Each column's list is headed by a header node, and the headers of the primary columns
+/// (which a solution must cover exactly once) are linked into a circular list headed by a root; the headers of
+/// secondary columns (which a solution may cover at most once) are not.  Nodes are held in an arena and linked by
+/// index, as a torus of `Rc` links would be a web of reference cycles.
+#[derive(Debug)]
+pub struct Dlx {
+    nodes: Vec<Links>,
+    sizes: Vec<usize>,
+    rows: usize,
+}
+
+impl Dlx {
+    /// Constructor for a matrix with no rows, `primary` primary columns (numbered from 0) and `secondary` secondary
+    /// columns (numbered after the primary columns).
+    pub fn new(primary: usize, secondary: usize) -> Self {
+        let columns = primary.saturating_add(secondary);
+        let mut nodes = Vec::with_capacity(columns.saturating_add(1));
+        nodes.push(Links {
+                       left: primary,
+                       right: match primary {
+                           0 => ROOT,
+                           _ => 1,
+                       },
+                       up: ROOT,
+                       down: ROOT,
+                       column: 0,
+                       row: 0,
+                   });
+        nodes.extend((0..columns).map(|column| {
+                                     let header = column.saturating_add(1);
+                                     let (left, right) = match column < primary {
+                                         true => (column,
+                                                  match header == primary {
+                                                      true => ROOT,
+                                                      false => header.saturating_add(1),
+                                                  }),
+                                         false => (header, header),
+                                     };
+                                     Links { left, right, up: header, down: header, column, row: 0 }
+                                 }));
+        Self {
+            nodes,
+            sizes: (0..columns).map(|_| 0).collect(),
+            rows: 0,
+        }
+    }
+
+    /// Appends a row with a 1 in each of `columns` (duplicates are ignored), returning the row's number (rows are
+    /// numbered from 0, in order of addition).  Returns an error if any column does not exist.
+    pub fn add_row(&mut self, columns: &[usize]) -> Result<usize> {
+        if let Some(&index) = columns.iter().find(|&&column| column >= self.columns()) {
+            return Err(Error::IndexOutOfBounds { op: Operation::AddRow, index, len: self.columns() });
+        }
+        let mut columns = columns.to_vec();
+        columns.sort_unstable();
+        columns.dedup();
+        let (row, first) = (self.rows, self.nodes.len());
+        let last = first.saturating_add(columns.len()).saturating_sub(1);
+        for (offset, &column) in columns.iter().enumerate() {
+            let (node, header) = (first.saturating_add(offset), column.saturating_add(1));
+            let up = self.links(header).up;
+            self.nodes.push(Links {
+                                left: match node == first {
+                                    true => last,
+                                    false => node.saturating_sub(1),
+                                },
+                                right: match node == last {
+                                    true => first,
+                                    false => node.saturating_add(1),
+                                },
+                                up,
+                                down: header,
+                                column,
+                                row,
+                            });
+            self.links_mut(up).down = node;
+            self.links_mut(header).up = node;
+            self.resize(column, usize::saturating_add);
+        }
+        self.rows = self.rows.saturating_add(1);
+        Ok(row)
+    }
+
+    /// The number of columns, primary and secondary.
+    pub fn columns(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Removes `column` from the matrix, along with every row which has a 1 in it, in O(1) per node unlinked.
+    /// Covered columns must be uncovered in the reverse order of their covering to restore the matrix.  Returns an
+    /// error if the column does not exist.
+    pub fn cover(&mut self, column: usize) -> Result<&mut Self> {
+        let header = self.header(column, Operation::Cover)?;
+        Ok(self.cover_header(header))
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Creates an `Iterator` over every exact cover of the matrix, searching lazily as each is requested.  Each
+    /// solution is the ascending list of its rows' numbers.  The matrix is fully restored once the `Iterator` is
+    /// exhausted or dropped.
+    pub fn solutions(&mut self) -> Solutions<'_> {
+        Solutions {
+            dlx: self,
+            stack: Vec::new(),
+            started: false,
+            exhausted: false,
+        }
+    }
+
+    /// Restores `column`, and the rows which have a 1 in it, to the matrix, reversing `cover()`.  Returns an error if
+    /// the column does not exist.
+    pub fn uncover(&mut self, column: usize) -> Result<&mut Self> {
+        let header = self.header(column, Operation::Uncover)?;
+        Ok(self.uncover_header(header))
+    }
+
+    /// Of the primary columns not yet covered, selects that with the fewest 1s (Knuth's "S heuristic").  Returns
+    /// `None` if every primary column is covered.
+    fn choose_column(&self) -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut header = self.links(ROOT).right;
+        while header != ROOT {
+            let size = self.size(header);
+            if best.is_none_or(|(_, best_size)| size < best_size) {
+                best = Some((header, size));
+            }
+            header = match size {
+                0 => ROOT,
+                _ => self.links(header).right,
+            };
+        }
+        best.map(|(header, _)| header)
+    }
+
+    /// Unlinks the column headed by `header` from the header list, and every row with a 1 in the column from the other
+    /// columns in which it has 1s.
+    fn cover_header(&mut self, header: usize) -> &mut Self {
+        let Links { left, right, .. } = self.links(header);
+        self.links_mut(right).left = left;
+        self.links_mut(left).right = right;
+        let mut row_node = self.links(header).down;
+        while row_node != header {
+            let mut node = self.links(row_node).right;
+            while node != row_node {
+                let Links { up, down, column, right, .. } = self.links(node);
+                self.links_mut(down).up = up;
+                self.links_mut(up).down = down;
+                self.resize(column, usize::saturating_sub);
+                node = right;
+            }
+            row_node = self.links(row_node).down;
+        }
+        self
+    }
+
+    /// Covers the columns, other than its own, in which the row of `row_node` has 1s.
+    fn cover_row(&mut self, row_node: usize) {
+        let mut node = self.links(row_node).right;
+        while node != row_node {
+            let Links { column, right, .. } = self.links(node);
+            self.cover_header(column.saturating_add(1));
+            node = right;
+        }
+    }
+
+    /// Returns the header of `column`, or an error attributed to `op` if the column does not exist.
+    fn header(&self, column: usize, op: Operation) -> Result<usize> {
+        match column < self.columns() {
+            true => Ok(column.saturating_add(1)),
+            false => Err(Error::IndexOutOfBounds { op, index: column, len: self.columns() }),
+        }
+    }
+
+    fn links(&self, node: usize) -> Links {
+        *self.nodes.get(node).expect(msg::ERR_INTERNAL_INVALID_INDEX)
+    }
+
+    fn links_mut(&mut self, node: usize) -> &mut Links {
+        self.nodes.get_mut(node).expect(msg::ERR_INTERNAL_INVALID_INDEX)
+    }
+
+    /// Applies `adjust` to the count of 1s in `column`.
+    fn resize(&mut self, column: usize, adjust: fn(usize, usize) -> usize) {
+        if let Some(size) = self.sizes.get_mut(column) {
+            *size = adjust(*size, 1);
+        }
+    }
+
+    /// The count of 1s in the column headed by `header`.
+    fn size(&self, header: usize) -> usize {
+        self.sizes
+            .get(self.links(header).column)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Relinks the column headed by `header`, reversing `cover_header()` by visiting its nodes in the reverse order.
+    fn uncover_header(&mut self, header: usize) -> &mut Self {
+        let mut row_node = self.links(header).up;
+        while row_node != header {
+            let mut node = self.links(row_node).left;
+            while node != row_node {
+                let Links { up, down, column, left, .. } = self.links(node);
+                self.resize(column, usize::saturating_add);
+                self.links_mut(down).up = node;
+                self.links_mut(up).down = node;
+                node = left;
+            }
+            row_node = self.links(row_node).up;
+        }
+        let Links { left, right, .. } = self.links(header);
+        self.links_mut(right).left = header;
+        self.links_mut(left).right = header;
+        self
+    }
+
+    /// Uncovers the columns, other than its own, in which the row of `row_node` has 1s, reversing `cover_row()`.
+    fn uncover_row(&mut self, row_node: usize) {
+        let mut node = self.links(row_node).left;
+        while node != row_node {
+            let Links { column, left, .. } = self.links(node);
+            self.uncover_header(column.saturating_add(1));
+            node = left;
+        }
+    }
+}
+
+/// `Solutions` iterates over the exact covers of a `Dlx` matrix, running Algorithm X without recursion: the rows
+/// selected so far are held on a stack, and the search resumes from where the previous solution was found.
+#[derive(Debug)]
+pub struct Solutions<'a> {
+    dlx: &'a mut Dlx,
+    stack: Vec<usize>,
+    started: bool,
+    exhausted: bool,
+}
+
+impl Solutions<'_> {
+    /// Retracts the most recently selected rows until one can be replaced by the next row of its column, and selects
+    /// that row.  Returns `false` (with the matrix restored) if every alternative has been tried.
+    fn backtrack(&mut self) -> bool {
+        while let Some(row_node) = self.stack.pop() {
+            self.dlx.uncover_row(row_node);
+            let Links { column, down, .. } = self.dlx.links(row_node);
+            let header = column.saturating_add(1);
+            match down == header {
+                true => { self.dlx.uncover_header(header); },
+                false => {
+                    self.dlx.cover_row(down);
+                    self.stack.push(down);
+                    return true;
+                },
+            }
+        }
+        false
+    }
+
+    /// Selects rows until every primary column is covered (returning `true`), or some column cannot be (returning
+    /// `false`).
+    fn descend(&mut self) -> bool {
+        while let Some(header) = self.dlx.choose_column() {
+            let row_node = self.dlx.links(header).down;
+            if row_node == header {
+                return false;
+            }
+            self.dlx.cover_header(header);
+            self.dlx.cover_row(row_node);
+            self.stack.push(row_node);
+        }
+        true
+    }
+}
+
+impl Iterator for Solutions<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Resuming after a solution first retracts its last selection
+        let mut retract = self.started;
+        self.started = true;
+        while !self.exhausted {
+            if retract && !self.backtrack() {
+                self.exhausted = true;
+                break;
+            }
+            retract = true;
+            if self.descend() {
+                let mut solution = self.stack.iter().map(|&row_node| self.dlx.links(row_node).row).collect::<Vec<_>>();
+                solution.sort_unstable();
+                return Some(solution);
+            }
+        }
+        None
+    }
+}
+
+/// Restores the matrix should iteration stop before every solution has been found.
+impl Drop for Solutions<'_> {
+    fn drop(&mut self) {
+        while let Some(row_node) = self.stack.pop() {
+            self.dlx.uncover_row(row_node);
+            let column = self.dlx.links(row_node).column;
+            self.dlx.uncover_header(column.saturating_add(1));
+        }
+    }
+}
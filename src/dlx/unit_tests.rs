@@ -0,0 +1,168 @@
+#![allow(clippy::unwrap_used, clippy::arithmetic_side_effects, clippy::indexing_slicing)]
+use super::*;
+
+/// Knuth's example matrix, whose only exact cover is rows 0, 3 and 4.
+fn knuth_example() -> Dlx {
+    let mut dlx = Dlx::new(7, 0);
+    [&[2, 4, 5][..], &[0, 3, 6], &[1, 2, 5], &[0, 3], &[1, 6], &[3, 4, 6]].iter()
+                                                                       .for_each(|row| { dlx.add_row(row).unwrap(); });
+    dlx
+}
+
+/// Builds the N-queens problem: one primary column per rank and file, and one secondary column per diagonal and
+/// anti-diagonal, which at most one queen may occupy.  Row `rank * n + file` places a queen on that square.
+fn n_queens(n: usize) -> Dlx {
+    let diagonals = 2 * n - 1;
+    let mut dlx = Dlx::new(2 * n, 2 * diagonals);
+    for rank in 0..n {
+        for file in 0..n {
+            dlx.add_row(&[rank, n + file, 2 * n + rank + file, 2 * n + diagonals + rank + n - 1 - file]).unwrap();
+        }
+    }
+    dlx
+}
+
+/// Builds a Sudoku puzzle (`0` marking an empty cell) as an exact-cover problem, with constraints that each cell holds
+/// a digit, and each row, column and box holds each digit.  Returns the problem, and the (row, column, digit) placed by
+/// each of its rows.
+fn sudoku(puzzle: &str) -> (Dlx, Vec<(usize, usize, usize)>) {
+    let mut dlx = Dlx::new(4 * 81, 0);
+    let mut placements = Vec::new();
+    for (cell, given) in puzzle.chars().map(|given| given.to_digit(10).unwrap() as usize).enumerate() {
+        let (row, column) = (cell / 9, cell % 9);
+        let boxed = (row / 3) * 3 + column / 3;
+        for digit in (0..9).filter(|&digit| given == 0 || given == digit + 1) {
+            dlx.add_row(&[cell, 81 + row * 9 + digit, 162 + column * 9 + digit, 243 + boxed * 9 + digit]).unwrap();
+            placements.push((row, column, digit + 1));
+        }
+    }
+    (dlx, placements)
+}
+
+#[test]
+fn solutions_finds_the_exact_cover_of_knuths_example() {
+    // given Knuth's example matrix
+    let mut sut = knuth_example();
+
+    // when its exact covers are sought
+    let result = sut.solutions().collect::<Vec<_>>();
+
+    // then the single solution is found
+    assert_eq!(result, vec![vec![0, 3, 4]]);
+}
+
+#[test]
+fn cover_and_uncover_restore_the_matrix() {
+    // given Knuth's example matrix
+    let mut sut = knuth_example();
+
+    // when columns are covered, then uncovered in reverse order
+    sut.cover(0).unwrap().cover(3).unwrap();
+    let covered = sut.solutions().collect::<Vec<_>>();
+    sut.uncover(3).unwrap().uncover(0).unwrap();
+
+    // then the rows covering the remaining columns are found while they are covered
+    assert_eq!(covered, vec![vec![0, 4]]);
+
+    // and the matrix is restored once they are uncovered
+    assert_eq!(sut.solutions().collect::<Vec<_>>(), vec![vec![0, 3, 4]]);
+    assert!(sut.sizes.iter().zip(&[2, 2, 2, 3, 2, 2, 3]).all(|(size, expected)| size == expected));
+}
+
+#[test]
+fn invalid_columns_are_rejected() {
+    // given a matrix of three columns
+    let mut sut = Dlx::new(2, 1);
+
+    // when a row, cover and uncover refer to a fourth column
+    let result = (sut.add_row(&[0, 3]).map(|_| ()), sut.cover(3).map(|_| ()), sut.uncover(4).map(|_| ()));
+
+    // then each is rejected
+    assert_eq!(result,
+               (Err(Error::IndexOutOfBounds { op: Operation::AddRow, index: 3, len: 3 }),
+                Err(Error::IndexOutOfBounds { op: Operation::Cover, index: 3, len: 3 }),
+                Err(Error::IndexOutOfBounds { op: Operation::Uncover, index: 4, len: 3 })));
+    assert_eq!(sut.rows(), 0);
+}
+
+#[test]
+fn a_matrix_without_a_cover_has_no_solutions() {
+    // given a matrix in which no row covers a primary column
+    let mut sut = Dlx::new(2, 0);
+    sut.add_row(&[0]).unwrap();
+    sut.add_row(&[0, 0]).unwrap();
+
+    // when its exact covers are sought
+    let result = sut.solutions().next();
+
+    // then there are none
+    assert_eq!(result, None);
+}
+
+#[test]
+fn n_queens_has_the_known_number_of_solutions() {
+    // given the N-queens problems for boards of 1 to 8 squares a side
+    let mut problems = (1..=8).map(n_queens).collect::<Vec<_>>();
+
+    // when their solutions are counted
+    let result = problems.iter_mut().map(|dlx| dlx.solutions().count()).collect::<Vec<_>>();
+
+    // then each has the known number of solutions
+    assert_eq!(result, vec![1, 0, 0, 2, 10, 4, 40, 92]);
+}
+
+#[test]
+fn n_queens_solutions_are_non_attacking() {
+    // given the 6-queens problem
+    let mut sut = n_queens(6);
+
+    // when its solutions are found
+    let solutions = sut.solutions().collect::<Vec<_>>();
+
+    // then each places six queens, no two sharing a rank, file or diagonal
+    assert!(solutions.iter().all(|solution| {
+        let queens = solution.iter().map(|row| (row / 6, row % 6)).collect::<Vec<_>>();
+        queens.len() == 6 &&
+        queens.iter().enumerate().all(|(i, &(rank, file))| {
+            queens[i + 1..].iter().all(|&(other_rank, other_file)| {
+                rank != other_rank &&
+                file != other_file &&
+                rank + other_file != other_rank + file &&
+                rank + file != other_rank + other_file
+            })
+        })
+    }));
+}
+
+#[test]
+fn dropping_solutions_early_restores_the_matrix() {
+    // given the 8-queens problem, of which only the first solution has been sought
+    let mut sut = n_queens(8);
+    let first = sut.solutions().next();
+    assert!(first.is_some());
+
+    // when every solution is sought afresh
+    let result = sut.solutions().count();
+
+    // then all are found
+    assert_eq!(result, 92);
+}
+
+#[test]
+fn sudoku_is_solved_uniquely() {
+    // given a Sudoku puzzle
+    let puzzle = "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+    let (mut sut, placements) = sudoku(puzzle);
+
+    // when its solutions are sought
+    let solutions = sut.solutions().collect::<Vec<_>>();
+
+    // then it has exactly one
+    assert_eq!(solutions.len(), 1);
+
+    // and it is the known solution
+    let mut grid = [[0; 9]; 9];
+    solutions[0].iter().map(|&row| placements[row]).for_each(|(row, column, digit)| grid[row][column] = digit);
+    let result = grid.iter().flatten().map(|digit| digit.to_string()).collect::<String>();
+    assert_eq!(result, "534678912672195348198342567859761423426853791713924856961537284287419635345286179");
+}
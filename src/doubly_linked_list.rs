@@ -1,16 +1,71 @@
+mod dot;
 mod iter;
+mod journal;
+mod live_iter;
+mod observer;
+mod pool;
+mod range;
+mod stats;
+// Helpers shared by the unit tests of `DoublyLinkedList` and its submodules
+#[cfg(test)]
+mod test_helpers;
+mod transaction;
 #[cfg(test)]
 mod unit_tests;
 use crate::{
     consts::*,
     Error,
     Node,
+    Operation,
     Result,
     NodeLink,
     WeakLink,
 };
-pub use self::iter::Iter;
-use std::rc::Rc;
+pub use self::{
+    iter::Iter,
+    live_iter::LiveIter,
+    observer::{
+        Mutation,
+        ObserverId,
+    },
+    pool::AllocationStats,
+    range::Range,
+    stats::{
+        ListStats,
+        RefCounts,
+    },
+    transaction::Transaction,
+};
+use self::{
+    journal::{
+        Action,
+        Journal,
+    },
+    live_iter::Marks,
+    observer::Observers,
+    pool::Pool,
+};
+use alloc::{
+    rc::Rc,
+    vec::Vec,
+};
+use core::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    cmp::Ordering,
+    iter::Rev,
+    result::Result as StdResult,
+};
+
+/// Selects the end of the list from which a positional search (e.g. `insert_sorted()`) begins.  Searching from the end
+/// nearer the expected position minimizes the number of `Node`s visited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchFrom {
+    Back,
+    Front,
+}
 
 /// `DoublyLinkedList` represents a series of `Node`s, provides appropriate data insertion and removal methods, and
 /// an permits iterating over the collection.
@@ -18,6 +73,16 @@ use std::rc::Rc;
 pub struct DoublyLinkedList<T> {
     head: Option<NodeLink<T>>,
     tail: Option<WeakLink<T>>,
+    // Identity token shared (weakly) with each owned `Node`, permitting O(1) validation of `NodeLink` arguments
+    id: Rc<()>,
+    // Count of structural modifications, shared with each `Iter` so that it can detect modification of the list
+    modifications: Rc<Cell<usize>>,
+    // Neighbours of `Node`s removed while a `LiveIter` exists, shared with each `LiveIter` so it can skip past them
+    marks: Marks<T>,
+    pool: Option<Pool<T>>,
+    stats: AllocationStats,
+    journal: Option<Journal<T>>,
+    observers: Observers<T>,
 }
 
 impl<T> DoublyLinkedList<T> {
@@ -26,6 +91,30 @@ impl<T> DoublyLinkedList<T> {
         Self {
             head: None,
             tail: None,
+            id: Rc::new(()),
+            modifications: Rc::new(Cell::new(0)),
+            marks: Marks::default(),
+            pool: None,
+            stats: AllocationStats::default(),
+            journal: None,
+            observers: Observers::new(),
+        }
+    }
+
+    /// Constructor for a list which recycles up to `capacity` `Node` allocations released by its removal methods,
+    /// rather than returning them to the heap, and reuses them for subsequent insertions.  Pooled `Node`s hold
+    /// `T::default()` while awaiting reuse.
+    pub fn with_pool(capacity: usize) -> Self where T: Default {
+        let mut list = Self::new();
+        list.pool = Some(Pool::new(capacity, T::default));
+        list
+    }
+
+    /// Returns counts of the `Node` allocations made by this list and of those served from its pool.
+    pub fn allocation_stats(&self) -> AllocationStats {
+        AllocationStats {
+            pooled: self.pool.as_ref().map_or(0, Pool::len),
+            ..self.stats
         }
     }
 
@@ -34,67 +123,99 @@ impl<T> DoublyLinkedList<T> {
         self.len() == 0
     }
 
+    /// Removes every `Node` from the list, dropping their `data`.  `Node`s with outstanding live references are
+    /// unlinked from the list, but their `data` is dropped only once the last `NodeLink` to them is released.
+    /// Observers are notified once, with `Mutation::Cleared`.
+    pub fn clear(&mut self) -> &mut Self {
+        if self.head.is_some() {
+            // Report the clear as a whole, rather than as a removal per `Node`
+            let observers = core::mem::replace(&mut self.observers, Observers::new());
+            self.journal_grouped(|list| {
+                while list.head.is_some() {
+                    let _ = list.pop_front();
+                }
+            });
+            self.observers = observers;
+            self.notify(&Mutation::Cleared);
+        }
+        self
+    }
+
     /// Inserts `data` as a `Node` into the list positionally after the `Node` referenced by `curr`.  If `curr`
     /// represents the tail of the list, this method delegates to `push_back()`, instead, so that the
-    /// `DoublyLinkedList`'s `tail` field is properly maintained.
-    pub fn insert_after(&mut self, curr: NodeLink<T>, data: T) -> &mut Self {
-        let old_next_opt = curr.borrow_mut().next.take();
-        match old_next_opt {
-            None => self.push_back(data),
-            Some(old_next) => {
-                let new_next = NodeLink::new(Node::new(data));
-
-                // update new next node's previous ref
-                new_next.borrow_mut().prev = old_next.borrow().prev.clone();
-
-                // update old next node's prev ref
-                old_next.borrow_mut().prev = Some(new_next.to_weak());
-
-                // update new next node's next ref
-                new_next.borrow_mut().next = Some(old_next);
-
-                // update current node's next ref
-                curr.borrow_mut().next = Some(new_next);
-
-                self
-            }
-        }
+    /// `DoublyLinkedList`'s `tail` field is properly maintained.  Returns an error, leaving the list unchanged, if
+    /// `curr` does not belong to this list.
+    pub fn insert_after(&mut self, curr: NodeLink<T>, data: T) -> Result<&mut Self> {
+        self.validate_owned(&curr, Operation::InsertAfter)?;
+        self.insert_after_link(curr, data);
+        Ok(self)
     }
 
     /// Inserts `data` as a `Node` into the list positionally before the `Node` referenced by `curr`.  If `curr`
     /// represents the head of the list, this method delegates to `push_front()`, instead, so that the
-    /// `DoublyLinkedList`'s `head` field is properly maintained.
-    pub fn insert_before(&mut self, curr: NodeLink<T>, data: T) -> &mut Self {
-        let old_prev_opt = curr.borrow_mut().prev.take();
-        match old_prev_opt {
-            None => self.push_front(data),
-            Some(weak) => {
-                let old_prev = weak.to_strong().expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE);
-                let new_prev = NodeLink::new(Node::new(data));
-
-                // update current node's previous ref
-                curr.borrow_mut().prev = Some(new_prev.to_weak());
+    /// `DoublyLinkedList`'s `head` field is properly maintained.  Returns an error, leaving the list unchanged, if
+    /// `curr` does not belong to this list.
+    pub fn insert_before(&mut self, curr: NodeLink<T>, data: T) -> Result<&mut Self> {
+        self.validate_owned(&curr, Operation::InsertBefore)?;
+        self.insert_before_link(curr, data);
+        Ok(self)
+    }
 
-                // update new previous node's previous and next refs
-                new_prev.borrow_mut().prev = Some(old_prev.to_weak());
-                new_prev.borrow_mut().next = old_prev.borrow().next.clone();
+    /// Inserts `data` as a `Node` into an ascending-ordered list, positioned after all `Node`s which do not compare
+    /// greater than it (so `Node`s with equal data retain their insertion order).  Ordering is `Node`'s `PartialOrd`;
+    /// incomparable data is treated as equal.  The search for the insertion point begins at the end of the list
+    /// specified by `hint`.  Returns a `NodeLink` to the inserted `Node`.
+    pub fn insert_sorted(&mut self, data: T, hint: SearchFrom) -> NodeLink<T> where T: PartialOrd {
+        self.insert_sorted_by(data, hint, |lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal))
+    }
 
-                // update old previous node's next ref
-                old_prev.borrow_mut().next = Some(new_prev);
+    /// Inserts `data` as a `Node` into a list ordered ascending according to `cmp`, positioned after all `Node`s
+    /// whose data does not compare greater than it.  The search for the insertion point begins at the end of the list
+    /// specified by `hint`.  Returns a `NodeLink` to the inserted `Node`.
+    pub fn insert_sorted_by<F>(&mut self, data: T, hint: SearchFrom, mut cmp: F) -> NodeLink<T>
+                              where F: FnMut(&T, &T) -> Ordering {
+        let node = self.insert_sorted_node(data, hint, |lhs, rhs| cmp(&lhs.data, &rhs.data));
+        self.journal_insertion(&node);
+        self.notify_inserted(&node);
+        node
+    }
 
-                self
-            }
-        }
+    /// Returns the maximum number of `Node` allocations the list's pool retains for reuse (0 if pooling is disabled).
+    pub fn pool_capacity(&self) -> usize {
+        self.pool.as_ref().map_or(0, Pool::capacity)
     }
 
     /// Creates an `Iterator` permitting iteration over the collection.
     pub fn iter(&self) -> Iter<T> {
-        Iter(self.head.clone())
+        Iter::new(self.head.clone(), &self.modifications)
+    }
+
+    /// Creates an `Iterator` over the `Node`s from `node` toward the head of the list, inclusive.  Returns an error if
+    /// `node` does not belong to this list.
+    pub fn iter_back_from(&self, node: &NodeLink<T>) -> Result<Rev<Range<T>>> {
+        let head = self.head.as_ref().ok_or(Error::NodeNotInList { op: Operation::IterBackFrom })?;
+        self.span(head, node, Operation::IterBackFrom)
+            .map(|len| Range::new(Some(head.clone()), Some(node.clone()), len).rev())
+    }
+
+    /// Creates an `Iterator` over the `Node`s from `node` toward the tail of the list, inclusive.  Returns an error if
+    /// `node` does not belong to this list.
+    pub fn iter_from(&self, node: &NodeLink<T>) -> Result<Range<T>> {
+        let tail = self.tail_link().ok_or(Error::NodeNotInList { op: Operation::IterFrom })?;
+        self.span(node, &tail, Operation::IterFrom)
+            .map(|len| Range::new(Some(node.clone()), Some(tail), len))
+    }
+
+    /// Creates an `Iterator` which, unlike `iter()`, tolerates (and skips) `Node`s removed during iteration, including
+    /// the `Node` it most recently yielded.  Suited to processing and removing `Node`s in a single pass.
+    pub fn live_iter(&self) -> LiveIter<T> {
+        LiveIter::new(self.head.as_ref(), &self.marks)
     }
 
     /// Returns the number of `Node`s currently in the list.  Note: this method does not check for the case where the
     /// number of `Nodes` > usize::MAX.  In such a case, this method will panic in debug and silently wrap in release
     /// (Rust/C++/C default behavior).
+    #[allow(clippy::arithmetic_side_effects)]
     pub fn len(&self) -> usize {
         let mut count = 0;
         let mut link_opt = self.head.clone();
@@ -105,6 +226,109 @@ impl<T> DoublyLinkedList<T> {
         count
     }
 
+    /// Merges the ascending-ordered `other` into this ascending-ordered list by relinking `other`'s `Node`s, in
+    /// O(n + m) and without allocation.  Ordering is by `PartialOrd` (incomparable data is treated as equal).  The
+    /// merge is stable: data from this list precedes equal data from `other`.  Outstanding `NodeLink`s to `other`'s
+    /// `Node`s remain valid, and refer to `Node`s of this list thereafter.
+    pub fn merge(&mut self, other: Self) -> &mut Self where T: PartialOrd {
+        self.merge_by(other, |lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal))
+    }
+
+    /// Merges `other` into this list as `merge()` does, where both lists are ordered ascending according to `cmp`.
+    pub fn merge_by<F>(&mut self, mut other: Self, mut cmp: F) -> &mut Self where F: FnMut(&T, &T) -> Ordering {
+        self.record_modification();
+        other.record_modification();
+        let mut adopted = Vec::new();
+        let mut cursor = self.head.clone();
+        let mut other_link_opt = other.head.take();
+        other.tail = None;
+        while let Some(node) = other_link_opt {
+            // Detach `node` from the remainder of `other`, and adopt it into this list
+            other_link_opt = node.borrow_mut().next.take();
+            node.borrow_mut().owner = Rc::downgrade(&self.id);
+
+            // Advance past every `Node` not greater than `node`; the cursor never moves backward, so merging is linear
+            while let Some(curr) = cursor.take() {
+                if cmp(&curr.borrow().data, &node.borrow().data) == Ordering::Greater {
+                    cursor = Some(curr);
+                    break;
+                }
+                cursor = curr.borrow().next.clone();
+            }
+            match &cursor {
+                Some(curr) => self.link_before(&node, curr),
+                None => self.link_back(&node),
+            }
+            if self.is_journaling() || self.is_observed() {
+                adopted.push(node);
+            }
+        }
+        let positions = self.adopted_positions(&adopted);
+        self.journal_adoptions(&positions);
+        adopted.iter().zip(positions).for_each(|(node, position)| self.notify(&Mutation::Inserted { node, position }));
+        self
+    }
+
+    /// Relinks the `Node` referenced by `node` so that it immediately follows the `Node` referenced by `anchor`.  The
+    /// `Node` (and its `data`) is neither reallocated nor moved, so all outstanding `NodeLink`s to it remain valid.
+    /// O(1).  Returns an error if either `Node` does not belong to this list.
+    pub fn move_after(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(node, Operation::MoveAfter)?;
+        self.validate_owned(anchor, Operation::MoveAfter)?;
+        if !Rc::ptr_eq(node, anchor) {
+            self.record_modification();
+            let from = self.tracked_position(node);
+            self.unlink(node);
+            self.link_after(node, anchor);
+            self.journal_move(node, from);
+            self.notify_moved(node, from);
+        }
+        Ok(self)
+    }
+
+    /// Relinks the `Node` referenced by `node` so that it immediately precedes the `Node` referenced by `anchor`.  The
+    /// `Node` (and its `data`) is neither reallocated nor moved, so all outstanding `NodeLink`s to it remain valid.
+    /// O(1).  Returns an error if either `Node` does not belong to this list.
+    pub fn move_before(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(node, Operation::MoveBefore)?;
+        self.validate_owned(anchor, Operation::MoveBefore)?;
+        if !Rc::ptr_eq(node, anchor) {
+            self.record_modification();
+            let from = self.tracked_position(node);
+            self.unlink(node);
+            self.link_before(node, anchor);
+            self.journal_move(node, from);
+            self.notify_moved(node, from);
+        }
+        Ok(self)
+    }
+
+    /// Relinks the `Node` referenced by `node` to the tail of the list in O(1), without reallocating it.  Returns an
+    /// error if the `Node` does not belong to this list.
+    pub fn move_to_back(&mut self, node: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(node, Operation::MoveToBack)?;
+        self.record_modification();
+        let from = self.tracked_position(node);
+        self.unlink(node);
+        self.link_back(node);
+        self.journal_move(node, from);
+        self.notify_moved(node, from);
+        Ok(self)
+    }
+
+    /// Relinks the `Node` referenced by `node` to the head of the list in O(1), without reallocating it.  Returns an
+    /// error if the `Node` does not belong to this list.
+    pub fn move_to_front(&mut self, node: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(node, Operation::MoveToFront)?;
+        self.record_modification();
+        let from = self.tracked_position(node);
+        self.unlink(node);
+        self.link_front(node);
+        self.journal_move(node, from);
+        self.notify_moved(node, from);
+        Ok(self)
+    }
+
     /// Removes the `Node` at the tail of the list and returns the `data` contained within.
     /// Note: Because of the way `RefCell` works, this will return an error if there is another live reference (e.g.
     /// caller also called `.iter()` and is holding a live `.borrow()`) to this and/or either of its adjacent (i.e.
@@ -112,10 +336,15 @@ impl<T> DoublyLinkedList<T> {
     pub fn pop_back(&mut self) -> Result<T> {
         self.tail
             .take()
-            .ok_or(Error::EmptyList)
-            .and_then(|weak| {
-                let old_tail = weak.to_strong()
-                                   .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE);
+            .ok_or(Error::EmptyList { op: Operation::PopBack })
+            // Upgrade (and release) the list's `tail` reference so it is not counted as a live reference below
+            .map(|weak| weak.to_strong()
+                            .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE))
+            .and_then(|old_tail| {
+                self.record_modification();
+                // The `Node` leaves the list even if extraction of its data fails below
+                self.journal_removal(&old_tail, |_, data| Action::PushBack(data));
+                self.bury(&old_tail);
                 // Set tail to point to extracted `Node`'s predecessor
                 self.tail = old_tail.borrow()
                                     .prev
@@ -127,16 +356,27 @@ impl<T> DoublyLinkedList<T> {
                                         None
                                     })
                                     // Otherwise, a predecessor exists.  Set it to be the new end-of-list `Node`
-                                    .and_then(|weak| {
+                                    .map(|weak| {
                                         let new_tail = weak.to_strong()
                                                            .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE);
                                         new_tail.borrow_mut().next = None;
-                                        Some(new_tail.to_weak())
+                                        new_tail.to_weak()
                                     });
+                // the extracted `Node` followed every `Node` remaining in the list
+                self.notify_removed(&old_tail, Self::len);
                 // Extract data from extracted `Node`.  If the extracted `Node` has outstanding live references, the
                 // runtime `borrowck` will (correctly) prevent extraction and this method will return an error
-                Rc::try_unwrap(old_tail.0).and_then(|ref_cell| Ok(ref_cell.into_inner().data))
-                                          .or_else(|rc| Err(Error::ExistingLiveReferences(Rc::strong_count(&rc))))
+                self.reclaim(old_tail)
+                    .map_err(|rc| {
+                        rc.borrow_mut().detach();
+                        Error::ExistingLiveReferences {
+                            op: Operation::PopBack,
+                            // the extracted `Node` followed every `Node` remaining in the list
+                            index: self.len(),
+                            strong: Rc::strong_count(&rc),
+                            weak: Rc::weak_count(&rc),
+                        }
+                    })
             })
     }
 
@@ -147,50 +387,500 @@ impl<T> DoublyLinkedList<T> {
     pub fn pop_front(&mut self) -> Result<T> {
         self.head
             .take()
-            .ok_or(Error::EmptyList)
+            .ok_or(Error::EmptyList { op: Operation::PopFront })
             .and_then(|link| {
+                self.record_modification();
+                self.journal_removal(&link, |_, data| Action::PushFront(data));
+                self.bury(&link);
                 self.head = link.borrow()
                                 .next
                                 .clone()
+                                // The new head (if any) no longer has a predecessor
+                                .inspect(|next| next.borrow_mut().prev = None)
                                 .or_else(|| {
                                     self.tail = None;
                                     None
                                 });
-                Rc::try_unwrap(link.0).and_then(|ref_cell| Ok(ref_cell.into_inner().data))
-                                      .or_else(|rc| Err(Error::ExistingLiveReferences(Rc::strong_count(&rc))))
+                self.notify_removed(&link, |_| 0);
+                self.reclaim(link)
+                    .map_err(|rc| {
+                        rc.borrow_mut().detach();
+                        Error::ExistingLiveReferences {
+                            op: Operation::PopFront,
+                            index: 0,
+                            strong: Rc::strong_count(&rc),
+                            weak: Rc::weak_count(&rc),
+                        }
+                    })
             })
     }
 
     /// Appends a `Node` to the end of the list.
     pub fn push_back(&mut self, data: T) -> &mut Self {
-        let mut node = Node::new(data);
+        self.push_back_link(data);
+        self
+    }
+
+    /// Prepends a `Node` to the front of the list.
+    pub fn push_front(&mut self, data: T) -> &mut Self {
+        self.push_front_link(data);
+        self
+    }
+
+    /// Creates a double-ended `Iterator` over the `Node`s from `from` up to, but excluding, `to`.  Use `.rev()` to
+    /// iterate backward from `to`'s predecessor to `from`.  Returns an error if either `Node` does not belong to this
+    /// list, or if `to` does not follow (or coincide with) `from`.  Validation walks the range, so this is O(k).
+    pub fn range(&self, from: &NodeLink<T>, to: &NodeLink<T>) -> Result<Range<T>> {
+        self.span(from, to, Operation::Range)
+            .map(|len| {
+                let back = to.borrow().prev.as_ref().and_then(WeakLink::to_strong);
+                Range::new(Some(from.clone()), back, len.saturating_sub(1))
+            })
+    }
+
+    /// Creates a double-ended `Iterator` over the `Node`s from `from` through `to`, inclusive.  Returns an error if
+    /// either `Node` does not belong to this list, or if `to` does not follow (or coincide with) `from`.  O(k).
+    pub fn range_inclusive(&self, from: &NodeLink<T>, to: &NodeLink<T>) -> Result<Range<T>> {
+        self.span(from, to, Operation::RangeInclusive)
+            .map(|len| Range::new(Some(from.clone()), Some(to.clone()), len))
+    }
+
+    /// Removes the `Node` referenced by `node` from the list and returns the `data` contained within.  Unlike
+    /// `pop_back()`/`pop_front()`, the list is left unmodified if the removal fails: an error is returned if the
+    /// `Node` does not belong to this list, or if any `NodeLink` to it (other than `node` itself) remains live.
+    pub fn remove(&mut self, node: NodeLink<T>) -> Result<T> {
+        self.validate_owned(&node, Operation::Remove)?;
+        // The list's own link and `node` account for two strong references; any others are held elsewhere
+        match Rc::strong_count(&node) {
+            strong if strong > 2 => Err(Error::ExistingLiveReferences {
+                                            op: Operation::Remove,
+                                            index: self.position(&node),
+                                            strong,
+                                            weak: Rc::weak_count(&node),
+                                        }),
+            _ => {
+                self.record_modification();
+                let position = self.tracked_position(&node);
+                self.journal_removal(&node, |_, data| Action::InsertAt(position.unwrap_or_default(), data));
+                // Kept to report where `node` was should its data prove unreclaimable once it is unlinked
+                let prev = node.borrow().prev.clone();
+                self.bury(&node);
+                self.unlink(&node);
+                node.borrow_mut().detach();
+                self.notify_removed(&node, |_| position.unwrap_or_default());
+                self.reclaim(node)
+                    .map_err(|rc| Error::ExistingLiveReferences {
+                        op: Operation::Remove,
+                        index: position.unwrap_or_else(|| {
+                            prev.as_ref()
+                                .and_then(WeakLink::to_strong)
+                                .map_or(0, |prev| self.position(&prev).saturating_add(1))
+                        }),
+                        strong: Rc::strong_count(&rc),
+                        weak: Rc::weak_count(&rc),
+                    })
+            },
+        }
+    }
+
+    /// Pre-allocates `Node`s into the list's pool so that at least `additional` subsequent insertions are served
+    /// without allocating, enabling pooling (and raising the pool's capacity) as necessary.
+    pub fn reserve(&mut self, additional: usize) -> &mut Self where T: Default {
+        let pool = self.pool.get_or_insert_with(|| Pool::new(0, T::default));
+        if pool.capacity() < additional {
+            pool.set_capacity(additional);
+        }
+        while pool.len() < additional && pool.fill() {
+            self.stats.allocated = self.stats.allocated.saturating_add(1);
+        }
+        self
+    }
+
+    /// Sets the maximum number of `Node` allocations the list's pool retains for reuse, enabling pooling if necessary.
+    /// Pooled allocations in excess of `capacity` are released.
+    pub fn set_pool_capacity(&mut self, capacity: usize) -> &mut Self where T: Default {
+        self.pool
+            .get_or_insert_with(|| Pool::new(0, T::default))
+            .set_capacity(capacity);
+        self
+    }
+
+    /// Releases every `Node` allocation held in the list's pool back to the heap.  The pool's capacity is unchanged.
+    pub fn shrink_to_fit(&mut self) -> &mut Self {
+        if let Some(pool) = self.pool.as_mut() {
+            pool.shrink_to_fit();
+        }
+        self
+    }
+
+    /// Exchanges the `data` held by the `Node`s referenced by `a` and `b`, leaving both `Node`s in place.  Returns an
+    /// error if either `Node` does not belong to this list.
+    pub fn swap_data(&mut self, a: &NodeLink<T>, b: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(a, Operation::SwapData)?;
+        self.validate_owned(b, Operation::SwapData)?;
+        if !Rc::ptr_eq(a, b) {
+            let positions = self.tracked_position(a).zip(self.tracked_position(b));
+            self.journal(|list| Action::SwapData(list.position(a), list.position(b)));
+            core::mem::swap(&mut a.borrow_mut().data, &mut b.borrow_mut().data);
+            if let (true, Some((a_position, b_position))) = (self.is_observed(), positions) {
+                self.notify(&Mutation::DataSwapped { a, a_position, b, b_position });
+            }
+        }
+        Ok(self)
+    }
+
+    /// Exchanges the positions of the `Node`s referenced by `a` and `b` in O(1) by relinking them; neither `Node` is
+    /// reallocated, so all outstanding `NodeLink`s remain valid.  Returns an error if either `Node` does not belong to
+    /// this list.
+    pub fn swap_nodes(&mut self, a: &NodeLink<T>, b: &NodeLink<T>) -> Result<&mut Self> {
+        self.validate_owned(a, Operation::SwapNodes)?;
+        self.validate_owned(b, Operation::SwapNodes)?;
+        if !Rc::ptr_eq(a, b) {
+            self.record_modification();
+            self.journal(|list| Action::Swap(list.position(a), list.position(b)));
+            // Order the pair so that `b` never immediately precedes `a`; `b`'s successor is then never `a` itself
+            let b_follows_a = b.borrow()
+                               .next
+                               .as_ref()
+                               .is_none_or(|next| !Rc::ptr_eq(next, a));
+            let (a, b) = match b_follows_a {
+                true => (a, b),
+                false => (b, a),
+            };
+            let b_next = b.borrow().next.clone();
+            let (a_from, b_from) = (self.tracked_position(a), self.tracked_position(b));
+
+            // Relink `b` into `a`'s position, then `a` into `b`'s former position
+            self.unlink(b);
+            self.link_before(b, a);
+            self.unlink(a);
+            match b_next {
+                Some(next) => self.link_before(a, &next),
+                None => self.link_back(a),
+            }
+            self.notify_moved(a, a_from);
+            self.notify_moved(b, b_from);
+        }
+        Ok(self)
+    }
+}
+
+// Private link-maintenance helpers.  Each operates in O(1) and preserves the list's invariants: `head` owns the first
+// `Node`, each `Node`'s `next` owns its successor, and `prev`/`tail` weakly reference the predecessor/last `Node`.
+impl<T> DoublyLinkedList<T> {
+    /// Returns `true` if `node` is currently linked into this list.
+    fn owns(&self, node: &NodeLink<T>) -> bool {
+        core::ptr::eq(node.borrow().owner.as_ptr(), Rc::as_ptr(&self.id))
+    }
+
+    /// Creates a detached `Node` tagged as belonging to this list, reusing a pooled allocation if one is available.
+    fn allocate(&mut self, data: T) -> NodeLink<T> {
+        let allocation = match self.pool.as_mut() {
+            Some(pool) => pool.take(data),
+            None => Err(data),
+        };
+        let link = match allocation {
+            Ok(allocation) => {
+                self.stats.reused = self.stats.reused.saturating_add(1);
+                NodeLink::from_strong(allocation)
+            },
+            Err(data) => {
+                self.stats.allocated = self.stats.allocated.saturating_add(1);
+                NodeLink::new(Node::new(data))
+            },
+        };
+        link.borrow_mut().owner = Rc::downgrade(&self.id);
+        link
+    }
+
+    /// Extracts the `data` from the unlinked `node`, retaining its allocation in the pool if there is room.  Returns
+    /// the allocation unchanged if `node` has outstanding live references.
+    fn reclaim(&mut self, node: NodeLink<T>) -> StdResult<T, Rc<RefCell<Node<T>>>> {
+        let allocation = match self.pool.as_mut() {
+            Some(pool) => pool.recycle(node.0),
+            None => Err(node.0),
+        };
+        allocation.or_else(|allocation| Rc::try_unwrap(allocation).map(|ref_cell| ref_cell.into_inner().data))
+    }
+
+    /// Returns a `NodeLink` to the `Node` at the head of the list, if any.
+    pub(crate) fn head_link(&self) -> Option<NodeLink<T>> {
+        self.head.clone()
+    }
+
+    /// As `insert_after()`, returning a `NodeLink` to the inserted `Node`.
+    pub(crate) fn insert_after_link(&mut self, curr: NodeLink<T>, data: T) -> NodeLink<T> {
+        self.record_modification();
+        let old_next_opt = curr.borrow_mut().next.take();
+        match old_next_opt {
+            None => self.push_back_link(data),
+            Some(old_next) => {
+                let new_next = self.allocate(data);
+
+                // update new next node's previous ref
+                new_next.borrow_mut().prev = old_next.borrow().prev.clone();
+
+                // update old next node's prev ref
+                old_next.borrow_mut().prev = Some(new_next.to_weak());
+
+                // update new next node's next ref
+                new_next.borrow_mut().next = Some(old_next);
+
+                // update current node's next ref
+                curr.borrow_mut().next = Some(new_next.clone());
+
+                self.journal(|list| Action::RemoveAt(list.position(&curr).saturating_add(1)));
+                self.notify_inserted(&new_next);
+                new_next
+            }
+        }
+    }
+
+    /// As `insert_before()`, returning a `NodeLink` to the inserted `Node`.
+    pub(crate) fn insert_before_link(&mut self, curr: NodeLink<T>, data: T) -> NodeLink<T> {
+        self.record_modification();
+        let old_prev_opt = curr.borrow_mut().prev.take();
+        match old_prev_opt {
+            None => self.push_front_link(data),
+            Some(weak) => {
+                let old_prev = weak.to_strong().expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE);
+                let new_prev = self.allocate(data);
+
+                // update current node's previous ref
+                curr.borrow_mut().prev = Some(new_prev.to_weak());
+
+                // update new previous node's previous and next refs
+                new_prev.borrow_mut().prev = Some(old_prev.to_weak());
+                new_prev.borrow_mut().next = old_prev.borrow().next.clone();
+
+                // update old previous node's next ref
+                old_prev.borrow_mut().next = Some(new_prev.clone());
+
+                self.journal(|list| Action::RemoveAt(list.position(&curr).saturating_sub(1)));
+                self.notify_inserted(&new_prev);
+                new_prev
+            }
+        }
+    }
+
+    /// As `push_back()`, returning a `NodeLink` to the appended `Node`.
+    pub(crate) fn push_back_link(&mut self, data: T) -> NodeLink<T> {
+        self.record_modification();
+        self.journal(|_| Action::PopBack);
+        let node_link = self.allocate(data);
         let old_tail = self.tail.take();
-        node.prev = old_tail.clone();
-        let node_link = NodeLink::new(node);
+        node_link.borrow_mut().prev = old_tail.clone();
         self.tail = Some(node_link.to_weak());
         match old_tail {
             Some(prev) => prev.to_strong()
                               .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE)
                               .borrow_mut()
-                              .next = Some(node_link),
-            None => self.head = Some(node_link),
+                              .next = Some(node_link.clone()),
+            None => self.head = Some(node_link.clone()),
         };
-        self
+        self.notify_inserted(&node_link);
+        node_link
     }
 
-    /// Prepends a `Node` to the front of the list.
-    pub fn push_front(&mut self, data: T) -> &mut Self {
-        let mut node = Node::new(data);
+    /// As `push_front()`, returning a `NodeLink` to the prepended `Node`.
+    pub(crate) fn push_front_link(&mut self, data: T) -> NodeLink<T> {
+        self.record_modification();
+        self.journal(|_| Action::PopFront);
+        let node_link = self.allocate(data);
         let old_head = self.head.take();
-        node.next = old_head.clone();
-        let node_link = NodeLink::new(node);
+        node_link.borrow_mut().next = old_head.clone();
         self.head = Some(node_link.clone());
         match old_head {
             Some(head) => head.borrow_mut()
                               .prev = Some(node_link.to_weak()),
             None => self.tail = Some(node_link.to_weak()),
         };
-        self
+        self.notify_inserted(&node_link);
+        node_link
+    }
+
+    /// Relinks the `Node` referenced by `node` from this list to the head of `dest` in O(1), without reallocating it.
+    /// Returns an error if the `Node` does not belong to this list.  Used only by `std`-dependent collections.
+    #[cfg(feature = "std")]
+    pub(crate) fn transfer_to_front(&mut self, node: &NodeLink<T>, dest: &mut Self) -> Result<()> {
+        self.validate_owned(node, Operation::MoveToFront)?;
+        self.record_modification();
+        let position = self.tracked_position(node);
+        self.journal_removal(node, |_, data| Action::InsertAt(position.unwrap_or_default(), data));
+        self.bury(node);
+        self.unlink(node);
+        self.notify_removed(node, |_| position.unwrap_or_default());
+        node.borrow_mut().owner = Rc::downgrade(&dest.id);
+        dest.record_modification();
+        dest.journal(|_| Action::PopFront);
+        dest.link_front(node);
+        dest.notify_inserted(node);
+        Ok(())
+    }
+
+    /// Returns a `NodeLink` to the `Node` at the tail of the list, if any.
+    pub(crate) fn tail_link(&self) -> Option<NodeLink<T>> {
+        self.tail.as_ref().and_then(WeakLink::to_strong)
+    }
+
+    /// Inserts `data` after the last `Node` which does not compare greater than it, searching from the end specified
+    /// by `hint`.
+    fn insert_sorted_node<F>(&mut self, data: T, hint: SearchFrom, mut cmp: F) -> NodeLink<T>
+                            where F: FnMut(&Node<T>, &Node<T>) -> Ordering {
+        self.record_modification();
+        let new_node = self.allocate(data);
+        match hint {
+            // Find the first `Node` greater than the new `Node`, and insert before it
+            SearchFrom::Front => {
+                let mut link_opt = self.head.clone();
+                while let Some(link) = link_opt {
+                    if cmp(&link.borrow(), &new_node.borrow()) == Ordering::Greater {
+                        self.link_before(&new_node, &link);
+                        return new_node;
+                    }
+                    link_opt = link.borrow().next.clone();
+                }
+                self.link_back(&new_node);
+            },
+            // Find the last `Node` not greater than the new `Node`, and insert after it
+            SearchFrom::Back => {
+                let mut link_opt = self.tail.as_ref().and_then(WeakLink::to_strong);
+                while let Some(link) = link_opt {
+                    if cmp(&link.borrow(), &new_node.borrow()) != Ordering::Greater {
+                        self.link_after(&new_node, &link);
+                        return new_node;
+                    }
+                    link_opt = link.borrow().prev.as_ref().and_then(WeakLink::to_strong);
+                }
+                self.link_front(&new_node);
+            },
+        }
+        new_node
+    }
+
+    /// Links the detached `node` into the list immediately after `anchor`.
+    fn link_after(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) {
+        let old_next = anchor.borrow_mut().next.take();
+        match &old_next {
+            Some(next) => next.borrow_mut().prev = Some(node.to_weak()),
+            None => self.tail = Some(node.to_weak()),
+        }
+        {
+            let mut new_node = node.borrow_mut();
+            new_node.prev = Some(anchor.to_weak());
+            new_node.next = old_next;
+        }
+        anchor.borrow_mut().next = Some(node.clone());
+    }
+
+    /// Links the detached `node` into the list at its tail.
+    fn link_back(&mut self, node: &NodeLink<T>) {
+        match self.tail.as_ref().and_then(WeakLink::to_strong) {
+            Some(tail) => self.link_after(node, &tail),
+            None => self.link_only(node),
+        }
+    }
+
+    /// Links the detached `node` into the list immediately before `anchor`.
+    fn link_before(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) {
+        let old_prev = anchor.borrow_mut()
+                             .prev
+                             .replace(node.to_weak())
+                             .map(|weak| weak.to_strong()
+                                             .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE));
+        {
+            let mut new_node = node.borrow_mut();
+            new_node.prev = old_prev.as_ref().map(NodeLink::to_weak);
+            new_node.next = Some(anchor.clone());
+        }
+        match old_prev {
+            Some(prev) => prev.borrow_mut().next = Some(node.clone()),
+            None => self.head = Some(node.clone()),
+        }
+    }
+
+    /// Links the detached `node` into the list at its head.
+    fn link_front(&mut self, node: &NodeLink<T>) {
+        match self.head.clone() {
+            Some(head) => self.link_before(node, &head),
+            None => self.link_only(node),
+        }
+    }
+
+    /// Links the detached `node` into an empty list.
+    fn link_only(&mut self, node: &NodeLink<T>) {
+        self.head = Some(node.clone());
+        self.tail = Some(node.to_weak());
+    }
+
+    /// Returns the zero-based position of `node` within the list (or the list's length if it is not in the list).
+    fn position(&self, node: &NodeLink<T>) -> usize {
+        self.iter()
+            .position(|link| Rc::ptr_eq(&link, node))
+            .unwrap_or_else(|| self.len())
+    }
+
+    /// Returns the position of `node` ahead of an edit, if it is needed by the journal or by observers.
+    fn tracked_position(&self, node: &NodeLink<T>) -> Option<usize> {
+        (self.is_journaling() || self.is_observed()).then(|| self.position(node))
+    }
+
+    /// Returns the positions of the `adopted` `Node`s, which must be in list order.  O(n).
+    fn adopted_positions(&self, adopted: &[NodeLink<T>]) -> Vec<usize> {
+        let mut pending = adopted.iter().peekable();
+        self.iter()
+            .enumerate()
+            .filter(|(_, node)| pending.next_if(|adoptee| Rc::ptr_eq(adoptee, node)).is_some())
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the number of `Node`s from `from` through `to`, inclusive, attributing any error to `op`.
+    fn span(&self, from: &NodeLink<T>, to: &NodeLink<T>, op: Operation) -> Result<usize> {
+        self.validate_owned(from, op)?;
+        self.validate_owned(to, op)?;
+        let mut count = 1_usize;
+        let mut link = from.clone();
+        while !Rc::ptr_eq(&link, to) {
+            let next = link.borrow().next.clone().ok_or(Error::NodeNotReachable { op })?;
+            link = next;
+            count = count.saturating_add(1);
+        }
+        Ok(count)
+    }
+
+    /// Invalidates every outstanding `Iter` over the list, in advance of a structural modification.
+    fn record_modification(&self) {
+        self.modifications.set(self.modifications.get().wrapping_add(1));
+    }
+
+    /// Unlinks `node` from its neighbours (or from `head`/`tail`), joining the neighbours to each other.  `node`
+    /// remains tagged as belonging to this list, so it is expected to be relinked or detached by the caller.
+    fn unlink(&mut self, node: &NodeLink<T>) {
+        let (old_prev, old_next) = {
+            let mut node = node.borrow_mut();
+            (node.prev
+                 .take()
+                 .map(|weak| weak.to_strong()
+                                 .expect(msg::ERR_INTERNAL_WEAK_UPGRADE_RACE)),
+             node.next.take())
+        };
+        match &old_next {
+            Some(next) => next.borrow_mut().prev = old_prev.as_ref().map(NodeLink::to_weak),
+            None => self.tail = old_prev.as_ref().map(NodeLink::to_weak),
+        }
+        match old_prev {
+            Some(prev) => prev.borrow_mut().next = old_next,
+            None => self.head = old_next,
+        }
+    }
+
+    /// Returns an error attributed to `op` if `node` is not currently linked into this list.
+    fn validate_owned(&self, node: &NodeLink<T>, op: Operation) -> Result<()> {
+        match self.owns(node) {
+            true => Ok(()),
+            false => Err(Error::NodeNotInList { op }),
+        }
     }
 }
 
@@ -201,6 +891,21 @@ impl<T> Default for DoublyLinkedList<T> {
     }
 }
 
+/// Unlinks the `Node`s front to back, freeing each as its predecessor's `next` is taken, so that dropping a long list
+/// does not recurse once per `Node`.  The walk stops at the first `Node` with a live reference held elsewhere, leaving
+/// it and its successors to be freed when that reference is dropped.
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        let mut node_opt = self.head.take();
+        while let Some(node) = node_opt {
+            node_opt = match Rc::strong_count(&node) {
+                1 => node.borrow_mut().next.take(),
+                _ => None,
+            };
+        }
+    }
+}
+
 /// Impl of total equality (marker trait) for `DoublyLinkedList`
 impl<T: Eq> Eq for DoublyLinkedList<T> {}
 
@@ -0,0 +1,86 @@
+use super::DoublyLinkedList;
+use crate::WeakLink;
+use alloc::{
+    format,
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
+use core::fmt::Debug;
+
+/// Describes where a weak (`prev` or `tail`) link actually points.
+enum Target {
+    Dangling,
+    Missing,
+    Position(usize),
+    Stray,
+}
+
+impl<T: Debug> DoublyLinkedList<T> {
+    /// Renders the list's structure as a Graphviz DOT digraph, for debugging link corruption.  Each `Node` is a vertex
+    /// labelled with its `data` and its strong and weak reference counts (excluding references held by this method);
+    /// `next` links are solid edges and `prev` links dashed.  `head` and `tail` are plaintext marker vertices.  Any
+    /// `prev` or `tail` link which does not refer to the expected `Node` (including one which is absent, dangles, or
+    /// refers to a `Node` outside the list) is drawn in red.
+    pub fn to_dot(&self) -> String {
+        let nodes = self.iter().collect::<Vec<_>>();
+        let target = |link: Option<&WeakLink<T>>| match link.map(WeakLink::to_strong) {
+            None => Target::Missing,
+            Some(None) => Target::Dangling,
+            Some(Some(node)) => nodes.iter()
+                                     .position(|candidate| Rc::ptr_eq(candidate, &node))
+                                     .map_or(Target::Stray, Target::Position),
+        };
+
+        let mut dot = String::from("digraph DoublyLinkedList {\n    rankdir=LR;\n    node [shape=box];\n    \
+                                    head [shape=plaintext];\n    tail [shape=plaintext];\n");
+        nodes.iter().enumerate().for_each(|(index, node)| {
+            dot.push_str(&format!("    n{} [label=\"{}\\nstrong: {}, weak: {}\"];\n",
+                                  index,
+                                  escape(&format!("{:?}", node.borrow().data)),
+                                  // `nodes` holds one strong reference to each `Node`
+                                  Rc::strong_count(node).saturating_sub(1),
+                                  Rc::weak_count(node)));
+        });
+
+        if !nodes.is_empty() {
+            dot.push_str("    head -> n0;\n");
+        }
+        nodes.iter().enumerate().for_each(|(index, node)| {
+            let node = node.borrow();
+            if node.next.is_some() {
+                dot.push_str(&format!("    n{} -> n{};\n", index, index.saturating_add(1)));
+            }
+            let from = format!("n{}", index);
+            push_weak_edge(&mut dot, &from, target(node.prev.as_ref()), index.checked_sub(1));
+        });
+        push_weak_edge(&mut dot, "tail", target(self.tail.as_ref()), nodes.len().checked_sub(1));
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Appends a dashed edge from `from` to `target`, highlighted in red unless `target` is the expected position
+/// (`None` meaning that no link is expected).
+fn push_weak_edge(dot: &mut String, from: &str, target: Target, expected: Option<usize>) {
+    let broken = |label: &str| format!("    {0}_broken [label=\"{1}\", color=red, fontcolor=red];\n    \
+                                        {0} -> {0}_broken [style=dashed, color=red];\n",
+                                       from,
+                                       label);
+    let edge = match (target, expected) {
+        (Target::Missing, None) => String::new(),
+        (Target::Missing, Some(_)) => broken("missing"),
+        (Target::Dangling, _) => broken("dangling"),
+        (Target::Stray, _) => broken("not in list"),
+        (Target::Position(position), Some(expected)) if position == expected =>
+            format!("    {} -> n{} [style=dashed];\n", from, position),
+        (Target::Position(position), _) => format!("    {} -> n{} [style=dashed, color=red];\n", from, position),
+    };
+    dot.push_str(&edge);
+}
+
+/// Escapes `text` for use within a double-quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+}
@@ -1,24 +1,59 @@
 #[cfg(test)]
 mod unit_tests;
-use crate::NodeLink;
+use crate::{
+    Error,
+    NodeLink,
+    Operation,
+    Result,
+};
+use alloc::rc::Rc;
+use core::cell::Cell;
 
+/// `Iter` iterates over a `DoublyLinkedList` from head to tail.  It snapshots the list's structural modification count
+/// on creation; `try_next()` fails fast if the list has since been modified, whereas `next()` does not check.
 #[derive(Debug)]
-pub struct Iter<T>(pub(super) Option<NodeLink<T>>);
+pub struct Iter<T> {
+    next: Option<NodeLink<T>>,
+    modifications: Rc<Cell<usize>>,
+    expected_modifications: usize,
+}
+
+impl<T> Iter<T> {
+    pub(super) fn new(head: Option<NodeLink<T>>, modifications: &Rc<Cell<usize>>) -> Self {
+        Self {
+            next: head,
+            modifications: Rc::clone(modifications),
+            expected_modifications: modifications.get(),
+        }
+    }
+
+    /// Predicate revealing whether the list has been structurally modified (e.g. by a push, pop, insertion, removal
+    /// or move) since this iterator was created.
+    pub fn is_invalidated(&self) -> bool {
+        self.modifications.get() != self.expected_modifications
+    }
+
+    /// Fail-fast counterpart to `next()`: returns an `Error::ConcurrentModification` instead of advancing if the list
+    /// has been structurally modified since this iterator was created.
+    pub fn try_next(&mut self) -> Result<Option<NodeLink<T>>> {
+        match self.is_invalidated() {
+            true => Err(Error::ConcurrentModification { op: Operation::TryNext }),
+            false => Ok(self.next()),
+        }
+    }
+}
 
 impl<T> Iterator for Iter<T> {
     type Item = NodeLink<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.take()
-              .and_then(|link| {
-                  self.0 = link.borrow().next.clone();
-                  Some(link)
-              })
+        self.next.take()
+                 .inspect(|link| self.next = link.borrow().next.clone())
     }
 }
 
 impl<T: PartialEq> PartialEq for Iter<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        self.0 == rhs.0
+        self.next == rhs.next
     }
 }
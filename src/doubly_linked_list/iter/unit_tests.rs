@@ -1,6 +1,8 @@
-#![allow(clippy::option_unwrap_used)]
+#![allow(clippy::unwrap_used)]
 use crate::{
     DoublyLinkedList,
+    Error,
+    Operation,
 };
 
 #[test]
@@ -40,3 +42,70 @@ fn iter_from_non_empty_list_returns_iterator_yielding_some_t() {
     // then the iterator should return `None`
     assert_eq!(result_2, None);
 }
+
+#[test]
+fn try_next_over_an_unmodified_list_yields_every_node() {
+    // given an iterator over a list which is not modified during iteration
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1).push_back(2);
+    let mut sut = list.iter();
+
+    // when the iterator is advanced fallibly to exhaustion
+    let result = (sut.try_next(), sut.try_next(), sut.try_next());
+
+    // then each node is yielded, followed by `None`
+    assert_eq!(result.0.unwrap().map(|link| link.borrow().data), Some(1));
+    assert_eq!(result.1.unwrap().map(|link| link.borrow().data), Some(2));
+    assert_eq!(result.2, Ok(None));
+}
+
+#[test]
+fn try_next_after_the_list_is_structurally_modified_fails_fast() {
+    // given an iterator which has begun iterating over a list
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1).push_back(2);
+    let mut sut = list.iter();
+    let _ = sut.try_next().unwrap();
+
+    // when the list is structurally modified
+    list.push_front(0);
+
+    // then the iterator reports the modification
+    assert!(sut.is_invalidated());
+    assert_eq!(sut.try_next(), Err(Error::ConcurrentModification { op: Operation::TryNext }));
+}
+
+#[test]
+fn try_next_detects_removal_and_moves() {
+    // given a list, and iterators created before each of a removal and a move
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1).push_back(2).push_back(3);
+    let mut before_removal = list.iter();
+    let node = list.iter().nth(1).unwrap();
+    list.remove(node).unwrap();
+    let mut before_move = list.iter();
+    let tail = list.iter().nth(1).unwrap();
+
+    // when the last of them is moved
+    list.move_to_front(&tail).unwrap();
+
+    // then both iterators report the modifications
+    assert!(before_removal.try_next().is_err());
+    assert!(before_move.try_next().is_err());
+}
+
+#[test]
+fn try_next_ignores_data_modification() {
+    // given an iterator over a list
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1).push_back(2);
+    let mut sut = list.iter();
+    let head = list.iter().next().unwrap();
+    let tail = list.iter().nth(1).unwrap();
+
+    // when only the list's data (not its structure) is modified
+    list.swap_data(&head, &tail).unwrap();
+
+    // then the iterator continues normally
+    assert_eq!(sut.try_next().unwrap().map(|link| link.borrow().data), Some(2));
+}
@@ -0,0 +1,415 @@
+#[cfg(test)]
+mod unit_tests;
+use super::DoublyLinkedList;
+use crate::{
+    consts::msg,
+    Error,
+    NodeLink,
+    Operation,
+    Result,
+};
+use alloc::vec::Vec;
+use core::{
+    mem,
+    result::Result as StdResult,
+};
+
+/// A structural edit, described positionally so that it remains applicable after intervening edits are undone (which
+/// may recreate `Node`s).  Applying an `Action` yields the `Action` which reverses it.
+#[derive(Debug)]
+pub(super) enum Action<T> {
+    /// The `Action`s reversing the edits of one compound operation (e.g. `clear()` or `merge()`), in the order the
+    /// edits were made.  They are applied in reverse order, as a unit.
+    Group(Vec<Action<T>>),
+    InsertAt(usize, T),
+    /// Moves the `Node` at position `from` so that it ends up at position `to`.
+    Move { from: usize, to: usize },
+    PopBack,
+    PopFront,
+    PushBack(T),
+    PushFront(T),
+    RemoveAt(usize),
+    Swap(usize, usize),
+    SwapData(usize, usize),
+}
+
+#[derive(Debug)]
+enum Entry<T> {
+    Action(Action<T>),
+    Checkpoint,
+}
+
+/// Records the inverse of each structural edit made to a `DoublyLinkedList`, so that edits can be undone and redone.
+/// Data removed from the list is returned to the caller, so the journal retains a copy made by `clone`.
+#[derive(Debug)]
+pub(super) struct Journal<T> {
+    undo: Vec<Entry<T>>,
+    redo: Vec<Entry<T>>,
+    clone: fn(&T) -> T,
+}
+
+/// The state of a list's journal at the start of a transaction, to which the list can be rolled back.
+#[derive(Debug)]
+pub(super) struct Savepoint<T> {
+    depth: usize,
+    redo: Vec<Entry<T>>,
+    temporary: bool,
+}
+
+impl<T> Journal<T> {
+    pub(super) fn new(clone: fn(&T) -> T) -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            clone,
+        }
+    }
+
+    /// Returns a copy of `data`, for retention by an `Action` which restores it.
+    pub(super) fn copy(&self, data: &T) -> T {
+        (self.clone)(data)
+    }
+
+    /// Records `inverse`, the `Action` which reverses a newly made edit.  Any undone edits can no longer be redone.
+    pub(super) fn record(&mut self, inverse: Action<T>) {
+        self.redo.clear();
+        self.undo.push(Entry::Action(inverse));
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Enables journaling: every subsequent edit (push, pop, insertion, removal, move, swap, clear or merge) is
+    /// recorded so that it can be reversed by `undo()` and reinstated by `redo()`.  Edits are recorded by position, so
+    /// an undone removal reinstates the removed `data` in a new `Node`.  A clear or a merge is journaled as a single
+    /// edit, so one `undo()` reverses it entirely.  Has no effect if journaling is enabled.
+    pub fn enable_journal(&mut self) -> &mut Self where T: Clone {
+        if self.journal.is_none() {
+            self.journal = Some(Journal::new(T::clone));
+        }
+        self
+    }
+
+    /// Disables journaling, discarding the journal's history.
+    pub fn disable_journal(&mut self) -> &mut Self {
+        self.journal = None;
+        self
+    }
+
+    /// Predicate revealing whether structural edits to the list are being journaled.
+    pub fn is_journaling(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    /// Predicate revealing whether there is a journaled edit to undo.
+    pub fn can_undo(&self) -> bool {
+        self.journal.as_ref().is_some_and(|journal| journal.undo.iter().any(|entry| matches!(entry, Entry::Action(_))))
+    }
+
+    /// Predicate revealing whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        self.journal.as_ref().is_some_and(|journal| journal.redo.iter().any(|entry| matches!(entry, Entry::Action(_))))
+    }
+
+    /// Marks the current state of the list as a checkpoint, delimiting the group of edits reversed by
+    /// `undo_to_checkpoint()` (and reinstated by `redo_to_checkpoint()`).  Has no effect if journaling is disabled.
+    pub fn checkpoint(&mut self) -> &mut Self {
+        if let Some(journal) = self.journal.as_mut() {
+            if !matches!(journal.undo.last(), None | Some(Entry::Checkpoint)) {
+                journal.undo.push(Entry::Checkpoint);
+            }
+        }
+        self
+    }
+
+    /// Reverses the most recent journaled edit.  Returns `false` if there was no edit to undo.  Returns an error (and
+    /// leaves the list and journal unchanged) if the edit cannot be reversed because a `Node` it would remove has
+    /// outstanding live references.
+    pub fn undo(&mut self) -> Result<bool> {
+        self.replay(Operation::Undo, false)
+    }
+
+    /// Reverses journaled edits back to the most recent checkpoint (or to the start of the journal).  Returns `false`
+    /// if there was no edit to undo.
+    pub fn undo_to_checkpoint(&mut self) -> Result<bool> {
+        self.replay(Operation::Undo, true)
+    }
+
+    /// Reinstates the most recently undone edit.  Returns `false` if there was no edit to redo.  Recording a new edit
+    /// discards all undone edits.
+    pub fn redo(&mut self) -> Result<bool> {
+        self.replay(Operation::Redo, false)
+    }
+
+    /// Reinstates undone edits up to the next checkpoint (or to the end of the journal).  Returns `false` if there was
+    /// no edit to redo.
+    pub fn redo_to_checkpoint(&mut self) -> Result<bool> {
+        self.replay(Operation::Redo, true)
+    }
+}
+
+// Private journaling helpers.
+impl<T> DoublyLinkedList<T> {
+    /// Records `inverse` if journaling is enabled.  `inverse` is only evaluated when journaling, as positional
+    /// `Action`s cost O(n) to describe.
+    pub(super) fn journal<F>(&mut self, inverse: F) where F: FnOnce(&Self) -> Action<T> {
+        if self.journal.is_some() {
+            let action = inverse(self);
+            if let Some(journal) = self.journal.as_mut() {
+                journal.record(action);
+            }
+        }
+    }
+
+    /// Records the insertion of the newly linked `node`.
+    pub(super) fn journal_insertion(&mut self, node: &NodeLink<T>) {
+        self.journal(|list| Action::RemoveAt(list.position(node)));
+    }
+
+    /// Records the insertion of newly linked `Node`s at `positions`, which must be ascending, as a single edit.  Their
+    /// removals are then undone in descending positional order, so each is undone without disturbing the positions of
+    /// those remaining.
+    pub(super) fn journal_adoptions(&mut self, positions: &[usize]) {
+        if !positions.is_empty() {
+            self.journal(|_| Action::Group(positions.iter().map(|&position| Action::RemoveAt(position)).collect()));
+        }
+    }
+
+    /// Makes the edits of `edits` and records them as a single edit.
+    pub(super) fn journal_grouped<F>(&mut self, edits: F) where F: FnOnce(&mut Self) {
+        let depth = self.journal.as_ref().map(|journal| journal.undo.len());
+        edits(self);
+        if let (Some(depth), Some(journal)) = (depth, self.journal.as_mut()) {
+            let group = journal.undo
+                               .drain(depth..)
+                               .filter_map(|entry| match entry {
+                                   Entry::Action(action) => Some(action),
+                                   Entry::Checkpoint => None,
+                               })
+                               .collect::<Vec<_>>();
+            if !group.is_empty() {
+                journal.undo.push(Entry::Action(Action::Group(group)));
+            }
+        }
+    }
+
+    /// Records the move of `node` from position `from` (as returned by `tracked_position()`).
+    pub(super) fn journal_move(&mut self, node: &NodeLink<T>, from: Option<usize>) {
+        if let Some(from) = from {
+            self.journal(|list| Action::Move { from: list.position(node), to: from });
+        }
+    }
+
+    /// Records the removal of `node` from the list; `inverse` receives a copy of its `data`.
+    pub(super) fn journal_removal<F>(&mut self, node: &NodeLink<T>, inverse: F) where F: FnOnce(&Self, T) -> Action<T> {
+        if let Some(copy) = self.journal.as_ref().map(|journal| journal.copy(&node.borrow().data)) {
+            self.journal(|list| inverse(list, copy));
+        }
+    }
+
+    /// Begins journaling a transaction (enabling journaling for its duration if necessary), returning the state to
+    /// which `rollback()` restores the list.
+    pub(super) fn savepoint(&mut self) -> Savepoint<T> where T: Clone {
+        let temporary = !self.is_journaling();
+        self.enable_journal();
+        let (depth, redo) = self.journal
+                                .as_mut()
+                                .map_or((0, Vec::new()), |journal| (journal.undo.len(), mem::take(&mut journal.redo)));
+        Savepoint { depth, redo, temporary }
+    }
+
+    /// Ends a transaction begun at `savepoint`, retaining its edits.
+    pub(super) fn commit(&mut self, savepoint: Savepoint<T>) {
+        match (savepoint.temporary, self.journal.as_mut()) {
+            (true, _) => self.journal = None,
+            // An empty transaction leaves previously undone edits redoable
+            (false, Some(journal)) if journal.undo.len() == savepoint.depth => journal.redo = savepoint.redo,
+            (false, _) => (),
+        }
+    }
+
+    /// Ends a transaction begun at `savepoint`, reverting every edit made since, and restoring the journal's history.
+    /// A `Node` inserted during the transaction is unlinked even if it has outstanding live references, so the list's
+    /// structure and data are always restored; `Node`s removed during the transaction are reinstated as new `Node`s.
+    pub(super) fn rollback(&mut self, savepoint: Savepoint<T>) {
+        if let Some(mut journal) = self.journal.take() {
+            while journal.undo.len() > savepoint.depth {
+                if let Some(Entry::Action(action)) = journal.undo.pop() {
+                    self.revert(action);
+                }
+            }
+            journal.redo = savepoint.redo;
+            if !savepoint.temporary {
+                self.journal = Some(journal);
+            }
+        }
+    }
+
+    /// Applies `action`, unlinking (rather than removing) any `Node` it fails to remove due to live references.
+    fn revert(&mut self, action: Action<T>) {
+        let failed = match action {
+            // Revert each `Action` of a group individually, so that one which fails does not hold back the others
+            Action::Group(actions) => {
+                actions.into_iter().rev().for_each(|action| self.revert(action));
+                None
+            },
+            action => self.apply(action).err(),
+        };
+        if let Some((action, _)) = failed {
+            let node = match action {
+                Action::PopBack => self.tail_link(),
+                Action::PopFront => self.head_link(),
+                Action::RemoveAt(index) => self.node_at(index).ok(),
+                _ => None,
+            };
+            if let Some(node) = node {
+                self.record_modification();
+                let position = self.position(&node);
+                self.bury(&node);
+                self.unlink(&node);
+                node.borrow_mut().detach();
+                self.notify_removed(&node, |_| position);
+            }
+        }
+    }
+
+    /// Moves one undo (or redo) step's `Action`s to the opposite history, applying each.  Checkpoints are carried
+    /// across so that the group they delimit can be replayed in the opposite direction.
+    fn replay(&mut self, op: Operation, to_checkpoint: bool) -> Result<bool> {
+        let mut journal = match self.journal.take() {
+            Some(journal) => journal,
+            None => return Ok(false),
+        };
+        let result = self.replay_with(&mut journal, op, to_checkpoint);
+        self.journal = Some(journal);
+        result
+    }
+
+    fn replay_with(&mut self, journal: &mut Journal<T>, op: Operation, to_checkpoint: bool) -> Result<bool> {
+        let (from, to) = match op {
+            Operation::Redo => (&mut journal.redo, &mut journal.undo),
+            _ => (&mut journal.undo, &mut journal.redo),
+        };
+        while let Some(Entry::Checkpoint) = from.last() {
+            from.pop();
+            to.push(Entry::Checkpoint);
+        }
+        let mut replayed = false;
+        while let Some(Entry::Action(_)) = from.last() {
+            if let Some(Entry::Action(action)) = from.pop() {
+                match self.apply(action) {
+                    Ok(inverse) => to.push(Entry::Action(inverse)),
+                    Err((action, err)) => {
+                        from.push(Entry::Action(action));
+                        return Err(err.in_op(op));
+                    },
+                }
+            }
+            replayed = true;
+            if !to_checkpoint {
+                break;
+            }
+        }
+        Ok(replayed)
+    }
+
+    /// Applies `action` (with journaling suspended), returning the `Action` which reverses it.  On failure, the list is
+    /// unchanged and `action` is returned with the error.
+    fn apply(&mut self, action: Action<T>) -> StdResult<Action<T>, (Action<T>, Error)> {
+        match action {
+            Action::Group(mut actions) => {
+                let mut inverses = Vec::with_capacity(actions.len());
+                while let Some(action) = actions.pop() {
+                    match self.apply(action) {
+                        Ok(inverse) => inverses.push(inverse),
+                        Err((action, err)) => {
+                            // Restore the list (and the group) by reversing the `Action`s already applied
+                            actions.push(action);
+                            while let Some(inverse) = inverses.pop() {
+                                let action = self.apply(inverse)
+                                                 .map_err(|(_, err)| err)
+                                                 .expect(msg::ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES);
+                                actions.push(action);
+                            }
+                            return Err((Action::Group(actions), err));
+                        },
+                    }
+                }
+                Ok(Action::Group(inverses))
+            },
+            Action::InsertAt(index, data) => match index.checked_sub(1) {
+                None => {
+                    self.push_front(data);
+                    Ok(Action::RemoveAt(0))
+                },
+                Some(prev) => match self.node_at(prev) {
+                    Ok(prev) => {
+                        self.insert_after_link(prev, data);
+                        Ok(Action::RemoveAt(index))
+                    },
+                    Err(err) => Err((Action::InsertAt(index, data), err)),
+                },
+            },
+            Action::Move { from, to } => self.move_to_position(from, to)
+                                             .map(|()| Action::Move { from: to, to: from })
+                                             .map_err(|err| (Action::Move { from, to }, err)),
+            Action::PopBack => self.tail_link()
+                                   .ok_or(Error::EmptyList { op: Operation::PopBack })
+                                   .and_then(|node| self.remove(node))
+                                   .map(Action::PushBack)
+                                   .map_err(|err| (Action::PopBack, err)),
+            Action::PopFront => self.head_link()
+                                    .ok_or(Error::EmptyList { op: Operation::PopFront })
+                                    .and_then(|node| self.remove(node))
+                                    .map(Action::PushFront)
+                                    .map_err(|err| (Action::PopFront, err)),
+            Action::PushBack(data) => {
+                self.push_back(data);
+                Ok(Action::PopBack)
+            },
+            Action::PushFront(data) => {
+                self.push_front(data);
+                Ok(Action::PopFront)
+            },
+            Action::RemoveAt(index) => self.node_at(index)
+                                           .and_then(|node| self.remove(node))
+                                           .map(|data| Action::InsertAt(index, data))
+                                           .map_err(|err| (Action::RemoveAt(index), err)),
+            Action::Swap(a, b) => self.node_at(a)
+                                      .and_then(|a| self.node_at(b).map(|b| (a, b)))
+                                      .and_then(|(a, b)| self.swap_nodes(&a, &b).map(|_| ()))
+                                      .map(|()| Action::Swap(a, b))
+                                      .map_err(|err| (Action::Swap(a, b), err)),
+            Action::SwapData(a, b) => self.node_at(a)
+                                          .and_then(|a| self.node_at(b).map(|b| (a, b)))
+                                          .and_then(|(a, b)| self.swap_data(&a, &b).map(|_| ()))
+                                          .map(|()| Action::SwapData(a, b))
+                                          .map_err(|err| (Action::SwapData(a, b), err)),
+        }
+    }
+
+    /// Moves the `Node` at position `from` so that it ends up at position `to`.
+    fn move_to_position(&mut self, from: usize, to: usize) -> Result<()> {
+        let node = self.node_at(from)?;
+        match to.checked_sub(1) {
+            _ if from == to => Ok(()),
+            None => self.move_to_front(&node).map(|_| ()),
+            // Moving toward the head, the new predecessor precedes `node`, so its position is unaffected by the move
+            Some(prev) if to < from => {
+                let anchor = self.node_at(prev)?;
+                self.move_after(&node, &anchor).map(|_| ())
+            },
+            // Moving toward the tail, the new predecessor follows `node`, so it is currently at position `to`
+            Some(_) => {
+                let anchor = self.node_at(to)?;
+                self.move_after(&node, &anchor).map(|_| ())
+            },
+        }
+    }
+
+    /// Returns a `NodeLink` to the `Node` at position `index`.
+    fn node_at(&self, index: usize) -> Result<NodeLink<T>> {
+        self.iter()
+            .nth(index)
+            .ok_or_else(|| Error::IndexOutOfBounds { op: Operation::Undo, index, len: self.len() })
+    }
+}
@@ -0,0 +1,266 @@
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+use crate::{
+    doubly_linked_list::test_helpers::{
+        forward,
+        list_of,
+    },
+    DoublyLinkedList,
+    Error,
+    Operation,
+    SearchFrom,
+};
+
+fn journaled_list_of(data: &[i32]) -> DoublyLinkedList<i32> {
+    let mut list = list_of(data);
+    list.enable_journal();
+    list
+}
+
+#[test]
+fn undo_without_journaling_does_nothing() {
+    // given a list which is not journaled
+    let mut list = DoublyLinkedList::new();
+    list.push_back(1);
+
+    // when an undo is requested
+    let result = list.undo();
+
+    // then nothing is undone
+    assert_eq!(result, Ok(false));
+    assert_eq!(forward(&list), vec![1]);
+}
+
+#[test]
+fn undo_and_redo_reverse_and_reinstate_pushes_and_pops() {
+    // given a journaled list which has been pushed to and popped from both ends
+    let mut list = journaled_list_of(&[2, 3]);
+    list.push_front(1).push_back(4);
+    let popped = (list.pop_front().unwrap(), list.pop_back().unwrap());
+    assert_eq!(popped, (1, 4));
+
+    // when every edit is undone
+    let undone = (0..5).map(|_| list.undo().unwrap()).collect::<Vec<_>>();
+
+    // then the four edits are reversed in turn, after which there is nothing to undo
+    assert_eq!(undone, vec![true, true, true, true, false]);
+    assert_eq!(forward(&list), vec![2, 3]);
+
+    // and when every edit is redone
+    while list.redo().unwrap() {}
+
+    // then the list is as it was after the edits
+    assert_eq!(forward(&list), vec![2, 3]);
+    assert!(!list.can_redo());
+    assert!(list.can_undo());
+}
+
+#[test]
+fn undo_reverses_each_edit_in_turn() {
+    // given a journaled list and a series of positional edits
+    let mut list = journaled_list_of(&[1, 2, 3, 4]);
+    let mut states = vec![forward(&list)];
+    let second = list.iter().nth(1).unwrap();
+    list.insert_after(second.clone(), 20).unwrap();
+    states.push(forward(&list));
+    list.insert_before(second.clone(), 10).unwrap();
+    states.push(forward(&list));
+    list.remove(second).unwrap();
+    states.push(forward(&list));
+    let head = list.iter().next().unwrap();
+    list.move_to_back(&head).unwrap();
+    states.push(forward(&list));
+    let tail = list.iter().last().unwrap();
+    let third = list.iter().nth(2).unwrap();
+    list.move_before(&tail, &third).unwrap();
+    states.push(forward(&list));
+    let (a, b) = (list.iter().next().unwrap(), list.iter().nth(4).unwrap());
+    list.swap_nodes(&a, &b).unwrap();
+    drop((head, tail, third, a, b));
+    states.push(forward(&list));
+    list.insert_sorted(0, SearchFrom::Front);
+    states.push(forward(&list));
+
+    // when each edit is undone, then the list returns through each of its previous states
+    states.pop();
+    while let Some(state) = states.pop() {
+        assert!(list.undo().unwrap());
+        assert_eq!(forward(&list), state);
+    }
+    assert_eq!(list.undo(), Ok(false));
+}
+
+#[test]
+fn redo_reinstates_undone_moves_and_swaps() {
+    // given a journaled list whose nodes have been moved and swapped, and the edits undone
+    let mut list = journaled_list_of(&[1, 2, 3, 4, 5]);
+    let nodes = list.iter().collect::<Vec<_>>();
+    list.move_after(&nodes[0], &nodes[3]).unwrap();
+    list.move_to_front(&nodes[4]).unwrap();
+    list.swap_nodes(&nodes[1], &nodes[2]).unwrap();
+    drop(nodes);
+    let edited = forward(&list);
+    while list.undo().unwrap() {}
+    assert_eq!(forward(&list), vec![1, 2, 3, 4, 5]);
+
+    // when the edits are redone
+    while list.redo().unwrap() {}
+
+    // then the edited order is restored
+    assert_eq!(forward(&list), edited);
+}
+
+#[test]
+fn a_new_edit_discards_undone_edits() {
+    // given a journaled list with an undone edit
+    let mut list = journaled_list_of(&[1]);
+    list.push_back(2);
+    list.undo().unwrap();
+
+    // when a new edit is made
+    list.push_back(3);
+
+    // then the undone edit can no longer be redone
+    assert_eq!(list.redo(), Ok(false));
+    assert_eq!(forward(&list), vec![1, 3]);
+}
+
+#[test]
+fn checkpoints_group_edits_for_undo_and_redo() {
+    // given a journaled list with two checkpointed groups of edits
+    let mut list = journaled_list_of(&[]);
+    list.push_back(1).push_back(2).checkpoint();
+    list.push_back(3).push_back(4).push_back(5).checkpoint();
+    list.push_back(6);
+
+    // when edits are undone to each checkpoint in turn
+    let result = (list.undo_to_checkpoint(), forward(&list), list.undo_to_checkpoint(), forward(&list));
+
+    // then each group is undone as a unit
+    assert_eq!(result, (Ok(true), vec![1, 2, 3, 4, 5], Ok(true), vec![1, 2]));
+
+    // and when edits are redone to a checkpoint
+    let result = (list.redo_to_checkpoint(), forward(&list));
+
+    // then the undone group is reinstated as a unit
+    assert_eq!(result, (Ok(true), vec![1, 2, 3, 4, 5]));
+}
+
+#[test]
+fn undo_of_a_merge_removes_the_merged_nodes() {
+    // given a journaled list into which another sorted list has been merged
+    let mut list = journaled_list_of(&[1, 4, 6]);
+    let mut other = DoublyLinkedList::new();
+    other.push_back(2).push_back(5).push_back(7);
+    list.checkpoint().merge(other);
+    assert_eq!(forward(&list), vec![1, 2, 4, 5, 6, 7]);
+
+    // when the merge is undone
+    let result = list.undo_to_checkpoint();
+
+    // then the merged nodes are removed
+    assert_eq!(result, Ok(true));
+    assert_eq!(forward(&list), vec![1, 4, 6]);
+
+    // and redoing the merge reinstates them
+    list.redo_to_checkpoint().unwrap();
+    assert_eq!(forward(&list), vec![1, 2, 4, 5, 6, 7]);
+}
+
+#[test]
+fn a_single_undo_reverses_an_entire_merge() {
+    // given a journaled list into which another sorted list has been merged
+    let mut list = journaled_list_of(&[1, 4, 6]);
+    let mut other = DoublyLinkedList::new();
+    other.push_back(2).push_back(5).push_back(7);
+    list.merge(other);
+
+    // when the merge is undone once
+    let result = list.undo();
+
+    // then every merged node is removed
+    assert_eq!(result, Ok(true));
+    assert_eq!(forward(&list), vec![1, 4, 6]);
+    assert!(!list.can_undo());
+
+    // and a single redo reinstates them all
+    assert_eq!(list.redo(), Ok(true));
+    assert_eq!(forward(&list), vec![1, 2, 4, 5, 6, 7]);
+    assert!(!list.can_redo());
+}
+
+#[test]
+fn a_single_undo_reverses_an_entire_clear() {
+    // given a journaled list which has been cleared
+    let mut list = journaled_list_of(&[1, 2, 3]);
+    list.clear();
+
+    // when the clear is undone once
+    let result = list.undo();
+
+    // then every node is reinstated, in order
+    assert_eq!(result, Ok(true));
+    assert_eq!(forward(&list), vec![1, 2, 3]);
+    assert!(!list.can_undo());
+
+    // and a single redo clears the list again
+    assert_eq!(list.redo(), Ok(true));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn an_undo_of_a_merge_blocked_by_a_live_reference_leaves_the_list_unchanged() {
+    // given a journaled list into which another list has been merged, with a reference to one merged node
+    let mut list = journaled_list_of(&[1, 3]);
+    let mut other = DoublyLinkedList::new();
+    other.push_back(2).push_back(4);
+    list.merge(other);
+    let merged = list.iter().nth(1).unwrap();
+
+    // when the merge is undone
+    let result = list.undo();
+
+    // then the undo fails, leaving every merged node in place
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::Undo, .. })));
+    assert_eq!(forward(&list), vec![1, 2, 3, 4]);
+
+    // and succeeds as a whole once the reference is released
+    drop(merged);
+    assert_eq!(list.undo(), Ok(true));
+    assert_eq!(forward(&list), vec![1, 3]);
+}
+
+#[test]
+fn undo_blocked_by_a_live_reference_leaves_the_list_and_journal_unchanged() {
+    // given a journaled list with an outstanding reference to a newly pushed node
+    let mut list = journaled_list_of(&[1]);
+    list.push_back(2);
+    let tail = list.iter().nth(1).unwrap();
+
+    // when the push is undone
+    let result = list.undo();
+
+    // then the undo fails
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::Undo, .. })));
+    assert_eq!(forward(&list), vec![1, 2]);
+
+    // and succeeds once the reference is released
+    drop(tail);
+    assert_eq!(list.undo(), Ok(true));
+    assert_eq!(forward(&list), vec![1]);
+}
+
+#[test]
+fn undo_of_a_failed_pop_reinstates_the_detached_data() {
+    // given a journaled list whose head was popped while referenced, detaching it
+    let mut list = journaled_list_of(&[1, 2]);
+    let head = list.iter().next().unwrap();
+    assert!(list.pop_front().is_err());
+    drop(head);
+
+    // when the pop is undone
+    let result = list.undo();
+
+    // then the data is reinstated
+    assert_eq!(result, Ok(true));
+    assert_eq!(forward(&list), vec![1, 2]);
+}
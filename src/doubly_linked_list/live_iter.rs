@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod unit_tests;
+use super::DoublyLinkedList;
+use crate::{
+    Node,
+    NodeLink,
+    WeakLink,
+};
+use alloc::{
+    collections::BTreeMap,
+    rc::Rc,
+    vec::Vec,
+};
+use core::cell::RefCell;
+
+/// The neighbours of a `Node` as of its removal from the list.
+#[derive(Debug)]
+pub(super) struct Tombstone<T> {
+    prev: Option<WeakLink<T>>,
+    next: Option<WeakLink<T>>,
+}
+
+/// A `Node` which some `LiveIter` may yet need to find its way past.
+#[derive(Debug)]
+pub(super) struct Mark<T> {
+    // The number of `LiveIter`s positioned at the `Node`, plus the number of `Tombstone`s naming it as a neighbour.
+    // Each hold is backed by a `WeakLink`, which keeps the `Node`'s allocation (and so its address, which keys the
+    // `Mark`) from being reused while the `Mark` exists
+    holds: usize,
+    // Recorded if the `Node` is removed from the list while held
+    tombstone: Option<Tombstone<T>>,
+}
+
+/// `Mark`s of the `Node`s which any `LiveIter` over a list can reach, keyed by `Node` address.  Shared between the
+/// list and each of its `LiveIter`s.  A `Mark` is dropped as soon as nothing holds its `Node`, so the map is empty
+/// whenever no `LiveIter` over the list exists.
+pub(super) type Marks<T> = Rc<RefCell<BTreeMap<*const RefCell<Node<T>>, Mark<T>>>>;
+
+/// `LiveIter` iterates over a `DoublyLinkedList` while tolerating structural modification of the list, including
+/// removal of the `Node` most recently yielded.  It holds only `WeakLink`s to `Node`s, so it neither prevents `Node`s
+/// from being removed nor keeps removed `Node`s alive.  Each call to `next()` follows the current `next` link of the
+/// most recently yielded `Node`.  If that `Node` has since been removed, iteration continues from the nearest `Node`
+/// which remains in the list, found by walking outward through the neighbours each removed `Node` had when it was
+/// removed (the list records these only for `Node`s which some `LiveIter` can still reach), so `Node`s removed after
+/// iteration began, including contiguous runs of them, are skipped.
+#[derive(Debug)]
+pub struct LiveIter<T> {
+    last: Option<WeakLink<T>>,
+    head: Option<WeakLink<T>>,
+    marks: Marks<T>,
+}
+
+impl<T> LiveIter<T> {
+    pub(super) fn new(head: Option<&NodeLink<T>>, marks: &Marks<T>) -> Self {
+        let head = head.map(NodeLink::to_weak);
+        if let Some(head) = head.as_ref() {
+            hold(&mut marks.borrow_mut(), head);
+        }
+        Self {
+            last: None,
+            head,
+            marks: Rc::clone(marks),
+        }
+    }
+
+    /// Finds the first `Node` remaining in the list which followed the removed `Node` referenced by `removed`: the
+    /// nearest surviving successor, or failing that, the current successor of the nearest surviving predecessor.
+    fn resume_after(&self, removed: &WeakLink<T>) -> Option<NodeLink<T>> {
+        let marks = self.marks.borrow();
+        let tombstone = |link: &WeakLink<T>| marks.get(&link.as_ptr()).and_then(|mark| mark.tombstone.as_ref());
+        let walk = |step: fn(&Tombstone<T>) -> &Option<WeakLink<T>>| {
+            let mut link = step(tombstone(removed)?).clone();
+            loop {
+                match live(&link) {
+                    Some(node) => break Some(node),
+                    None => link = step(tombstone(&link?)?).clone(),
+                }
+            }
+        };
+        walk(|tombstone| &tombstone.next)
+            .or_else(|| walk(|tombstone| &tombstone.prev).and_then(|pred| pred.borrow().next.clone()))
+    }
+}
+
+/// Upgrades `link` if its `Node` is still alive and linked into a list.
+fn live<T>(link: &Option<WeakLink<T>>) -> Option<NodeLink<T>> {
+    link.as_ref()
+        .and_then(WeakLink::to_strong)
+        .filter(|node| node.borrow().owner.strong_count() > 0)
+}
+
+/// Records one more hold on the `Node` referenced by `node`.
+fn hold<T>(marks: &mut BTreeMap<*const RefCell<Node<T>>, Mark<T>>, node: &WeakLink<T>) {
+    let mark = marks.entry(node.as_ptr())
+                    .or_insert_with(|| Mark { holds: 0, tombstone: None });
+    mark.holds = mark.holds.saturating_add(1);
+}
+
+/// Releases one hold on the `Node` referenced by `node`.  A `Mark` left with no holds is dropped, releasing in turn
+/// the neighbours named by its `Tombstone`.
+fn release<T>(marks: &mut BTreeMap<*const RefCell<Node<T>>, Mark<T>>, node: &WeakLink<T>) {
+    let mut pending = Vec::from([node.as_ptr()]);
+    while let Some(ptr) = pending.pop() {
+        let released = marks.get_mut(&ptr).is_some_and(|mark| {
+            mark.holds = mark.holds.saturating_sub(1);
+            mark.holds == 0
+        });
+        if let Some(Mark { tombstone: Some(tombstone), .. }) = released.then(|| marks.remove(&ptr)).flatten() {
+            pending.extend(tombstone.prev.iter().chain(tombstone.next.iter()).map(WeakLink::as_ptr));
+        }
+    }
+}
+
+impl<T> Iterator for LiveIter<T> {
+    type Item = NodeLink<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match (live(&self.last), &self.last) {
+            (Some(last), _) => last.borrow().next.clone(),
+            (None, Some(removed)) => self.resume_after(removed),
+            (None, None) => live(&self.head).or_else(|| self.head.as_ref().and_then(|head| self.resume_after(head))),
+        };
+        // Once yielded, a `Node` is only ever followed; the head is needed only until the first `Node` is yielded
+        let prior = self.head.take().or_else(|| self.last.take());
+        self.last = next.as_ref().map(NodeLink::to_weak);
+        let mut marks = self.marks.borrow_mut();
+        if let Some(last) = self.last.as_ref() {
+            hold(&mut marks, last);
+        }
+        if let Some(prior) = prior {
+            release(&mut marks, &prior);
+        }
+        next
+    }
+}
+
+/// Releases the `LiveIter`'s hold on its position, so that the list stops recording removals it no longer needs.
+impl<T> Drop for LiveIter<T> {
+    fn drop(&mut self) {
+        if let Some(prior) = self.head.take().or_else(|| self.last.take()) {
+            release(&mut self.marks.borrow_mut(), &prior);
+        }
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Records the neighbours of `node`, which is about to be removed, if some `LiveIter` may later need to find its
+    /// way past it (i.e. if `node` is held).
+    pub(super) fn bury(&self, node: &NodeLink<T>) {
+        let mut marks = self.marks.borrow_mut();
+        if let Some(mark) = marks.get_mut(&Rc::as_ptr(node)) {
+            let tombstone = Tombstone {
+                prev: node.borrow().prev.clone(),
+                next: node.borrow().next.as_ref().map(NodeLink::to_weak),
+            };
+            let neighbours = tombstone.prev.iter().chain(tombstone.next.iter()).cloned().collect::<Vec<_>>();
+            let buried = mark.tombstone.replace(tombstone);
+            neighbours.iter().for_each(|neighbour| hold(&mut marks, neighbour));
+            // A `Node` relinked into the list since it was last removed no longer needs its former neighbours
+            if let Some(buried) = buried {
+                buried.prev.iter().chain(buried.next.iter()).for_each(|neighbour| release(&mut marks, neighbour));
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+#![allow(clippy::unwrap_used)]
+use crate::doubly_linked_list::test_helpers::{
+    forward,
+    list_of,
+};
+
+#[test]
+fn live_iter_over_an_unmodified_list_yields_every_node_in_order() {
+    // given a list
+    let list = list_of(&[1, 2, 3]);
+
+    // when it is iterated with a live iterator
+    let result = list.live_iter().map(|link| link.borrow().data).collect::<Vec<_>>();
+
+    // then every node is yielded in order
+    assert_eq!(result, vec![1, 2, 3]);
+}
+
+#[test]
+fn live_iter_permits_removing_each_yielded_node() {
+    // given a list
+    let mut list = list_of(&[1, 2, 3, 4, 5]);
+    let mut removed = Vec::new();
+
+    // when every even node is removed as it is yielded
+    let sut = list.live_iter();
+    for node in sut {
+        if node.borrow().data % 2 == 0 {
+            removed.push(list.remove(node).unwrap());
+        }
+    }
+
+    // then every removal succeeds
+    assert_eq!(removed, vec![2, 4]);
+
+    // and the odd nodes remain
+    assert_eq!(forward(&list), vec![1, 3, 5]);
+}
+
+#[test]
+fn live_iter_skips_nodes_removed_ahead_of_it() {
+    // given a list and a live iterator which has yielded its head
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let mut sut = list.live_iter();
+    let _ = sut.next();
+
+    // when the following node is removed
+    let node = list.iter().nth(1).unwrap();
+    list.remove(node).unwrap();
+
+    // then iteration continues past the removed node
+    assert_eq!(sut.map(|link| link.borrow().data).collect::<Vec<_>>(), vec![3, 4]);
+}
+
+#[test]
+fn live_iter_continues_from_the_predecessor_when_the_yielded_node_and_its_successor_are_removed() {
+    // given a list and a live iterator which has yielded its second node
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let mut sut = list.live_iter();
+    let yielded = sut.nth(1).unwrap();
+
+    // when the yielded node and its successor are removed
+    let successor = list.iter().nth(2).unwrap();
+    list.remove(successor).unwrap();
+    list.remove(yielded).unwrap();
+
+    // then iteration continues from the surviving predecessor's successor
+    assert_eq!(sut.next().map(|link| link.borrow().data), Some(4));
+    assert!(sut.next().is_none());
+}
+
+#[test]
+fn live_iter_skips_a_removed_run_surrounding_the_yielded_node() {
+    // given a list and a live iterator which has yielded its third node
+    let mut list = list_of(&[1, 2, 3, 4, 5]);
+    let mut sut = list.live_iter();
+    let yielded = sut.nth(2).unwrap();
+
+    // when the yielded node, its predecessor and its successor are removed
+    let predecessor = list.iter().nth(1).unwrap();
+    let successor = list.iter().nth(3).unwrap();
+    list.remove(predecessor).unwrap();
+    list.remove(yielded).unwrap();
+    list.remove(successor).unwrap();
+
+    // then iteration continues with the first node beyond the removed run
+    assert_eq!(sut.map(|link| link.borrow().data).collect::<Vec<_>>(), vec![5]);
+    assert_eq!(forward(&list), vec![1, 5]);
+}
+
+#[test]
+fn live_iter_continues_from_the_predecessor_when_every_node_after_the_yielded_node_is_removed() {
+    // given a list and a live iterator which has yielded its third node
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let mut sut = list.live_iter();
+    let yielded = sut.nth(2).unwrap();
+
+    // when the yielded node, its neighbours and every node after it are removed, and a node is appended
+    let predecessor = list.iter().nth(1).unwrap();
+    list.remove(predecessor).unwrap();
+    list.remove(yielded).unwrap();
+    list.pop_back().unwrap();
+    list.push_back(5);
+
+    // then iteration continues from the successor of the nearest surviving predecessor
+    assert_eq!(sut.map(|link| link.borrow().data).collect::<Vec<_>>(), vec![5]);
+}
+
+#[test]
+fn live_iter_starts_beyond_a_removed_run_at_the_head() {
+    // given a list and a live iterator which has not yet yielded a node
+    let mut list = list_of(&[1, 2, 3]);
+    let sut = list.live_iter();
+
+    // when the first two nodes are removed
+    list.pop_front().unwrap();
+    list.pop_front().unwrap();
+
+    // then iteration begins with the first remaining node
+    assert_eq!(sut.map(|link| link.borrow().data).collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+fn live_iter_yields_nodes_inserted_ahead_of_it() {
+    // given a list and a live iterator which has yielded its head
+    let mut list = list_of(&[1, 3]);
+    let mut sut = list.live_iter();
+    let head = sut.next().unwrap();
+
+    // when a node is inserted after the yielded node
+    list.insert_after(head, 2).unwrap();
+
+    // then the inserted node is yielded
+    assert_eq!(sut.map(|link| link.borrow().data).collect::<Vec<_>>(), vec![2, 3]);
+}
+
+#[test]
+fn live_iter_does_not_prevent_popping() {
+    // given a list and a live iterator which has yielded (and released) its head
+    let mut list = list_of(&[1, 2]);
+    let mut sut = list.live_iter();
+    drop(sut.next());
+
+    // when the remaining nodes are popped from either end
+    let result = (list.pop_front(), list.pop_back());
+
+    // then both pops succeed
+    assert_eq!(result, (Ok(1), Ok(2)));
+
+    // and the iterator is exhausted
+    assert!(sut.next().is_none());
+}
+
+#[test]
+fn live_iter_leaves_no_marks_once_iteration_ends() {
+    // given a list and a live iterator over it
+    let mut list = list_of(&[1, 2, 3, 4, 5, 6]);
+    let mut sut = list.live_iter();
+
+    // when each yielded node, and the node following each odd one, is removed during iteration
+    for node in sut.by_ref() {
+        let ahead = (node.borrow().data % 2 == 1).then(|| node.borrow().next.clone()).flatten();
+        list.remove(node).unwrap();
+        if let Some(ahead) = ahead {
+            list.remove(ahead).unwrap();
+        }
+    }
+
+    // then every node has been removed
+    assert!(list.is_empty());
+
+    // and, although the iterator still exists, the list no longer records any node for it
+    assert!(list.marks.borrow().is_empty());
+    drop(sut);
+}
+
+#[test]
+fn a_long_lived_live_iter_marks_only_the_nodes_it_can_reach() {
+    // given a long list and a live iterator which has yielded its head
+    let mut list = list_of(&(0..100).collect::<Vec<_>>());
+    let mut sut = list.live_iter();
+    let head = sut.next().unwrap();
+
+    // when every node beyond the second is removed
+    (2..100).for_each(|_| { list.pop_back().unwrap(); });
+
+    // then only the iterator's position is marked
+    assert_eq!(list.marks.borrow().len(), 1);
+
+    // and when the yielded node is removed, only it and its successor are marked
+    list.remove(head).unwrap();
+    assert_eq!(list.marks.borrow().len(), 2);
+
+    // and once the iterator moves on, only its new position is marked
+    assert_eq!(sut.next().map(|link| link.borrow().data), Some(1));
+    assert_eq!(list.marks.borrow().len(), 1);
+
+    // and once the iterator is dropped, nothing is marked
+    drop(sut);
+    assert!(list.marks.borrow().is_empty());
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod unit_tests;
+use super::DoublyLinkedList;
+use crate::NodeLink;
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result as FmtResult,
+};
+
+/// Describes a completed edit to a `DoublyLinkedList`, as reported to its observers.  Positions are zero-based.
+#[derive(Debug)]
+pub enum Mutation<'a, T> {
+    /// Every `Node` was removed from the list.
+    Cleared,
+    /// The `data` of the `Node`s `a` (at `a_position`) and `b` (at `b_position`) was exchanged.
+    DataSwapped { a: &'a NodeLink<T>, a_position: usize, b: &'a NodeLink<T>, b_position: usize },
+    /// `node` was inserted at `position`.
+    Inserted { node: &'a NodeLink<T>, position: usize },
+    /// `node` was moved from position `from` to position `to`.
+    Moved { node: &'a NodeLink<T>, from: usize, to: usize },
+    /// The `Node` at `position`, holding `data`, was removed.
+    Removed { data: &'a T, position: usize },
+}
+
+/// Identifies an observer registered with `DoublyLinkedList::observe()`, for use with `unobserve()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObserverId(usize);
+
+type Observer<T> = Box<dyn FnMut(&Mutation<'_, T>)>;
+
+/// The observers registered with a `DoublyLinkedList`.
+pub(super) struct Observers<T> {
+    entries: Vec<(ObserverId, Observer<T>)>,
+    next_id: usize,
+}
+
+impl<T> Observers<T> {
+    pub(super) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+/// `Debug` cannot be derived, as observers are closures.
+impl<T> Debug for Observers<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Observers")
+         .field("len", &self.entries.len())
+         .field("next_id", &self.next_id)
+         .finish()
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Registers `observer` to be notified after each insertion, removal, move, data swap and clear, with the affected
+    /// `Node` and its position.  Observers run once the edit has completed, so the list is always consistent when
+    /// they are invoked.  An observer cannot re-enter the list it observes: it is not given access to the list, and
+    /// must be `'static`, so it can only reach the list through shared ownership (e.g. `Rc<RefCell<_>>`), which the
+    /// edit being reported holds mutably borrowed.  Reporting positions costs O(n) per edit while any observer is
+    /// registered.
+    pub fn observe<F>(&mut self, observer: F) -> ObserverId where F: FnMut(&Mutation<'_, T>) + 'static {
+        let id = ObserverId(self.observers.next_id);
+        self.observers.next_id = self.observers.next_id.wrapping_add(1);
+        self.observers.entries.push((id, Box::new(observer)));
+        id
+    }
+
+    /// Deregisters the observer identified by `id`.  Returns `false` if no such observer was registered.
+    pub fn unobserve(&mut self, id: ObserverId) -> bool {
+        let len = self.observers.entries.len();
+        self.observers.entries.retain(|(entry_id, _)| *entry_id != id);
+        self.observers.entries.len() != len
+    }
+}
+
+// Private notification helpers.
+impl<T> DoublyLinkedList<T> {
+    /// Predicate revealing whether any observer is registered.
+    pub(super) fn is_observed(&self) -> bool {
+        !self.observers.entries.is_empty()
+    }
+
+    /// Reports `mutation` to every observer.
+    pub(super) fn notify(&mut self, mutation: &Mutation<'_, T>) {
+        self.observers.entries.iter_mut().for_each(|(_, observer)| observer(mutation));
+    }
+
+    /// Reports the insertion of the newly linked `node`.
+    pub(super) fn notify_inserted(&mut self, node: &NodeLink<T>) {
+        if self.is_observed() {
+            let position = self.position(node);
+            self.notify(&Mutation::Inserted { node, position });
+        }
+    }
+
+    /// Reports the move of `node` from position `from` (as returned by `tracked_position()`).
+    pub(super) fn notify_moved(&mut self, node: &NodeLink<T>, from: Option<usize>) {
+        if let (true, Some(from)) = (self.is_observed(), from) {
+            let to = self.position(node);
+            self.notify(&Mutation::Moved { node, from, to });
+        }
+    }
+
+    /// Reports the removal of the unlinked `node` from the `position` it occupied.
+    pub(super) fn notify_removed<F>(&mut self, node: &NodeLink<T>, position: F) where F: FnOnce(&Self) -> usize {
+        if self.is_observed() {
+            let position = position(self);
+            let node = node.borrow();
+            self.notify(&Mutation::Removed { data: &node.data, position });
+        }
+    }
+}
@@ -0,0 +1,173 @@
+#![allow(clippy::unwrap_used)]
+use crate::{
+    DoublyLinkedList,
+    Error,
+    Mutation,
+    Operation,
+    SearchFrom,
+};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+/// An owned summary of a `Mutation<i32>`.
+#[derive(Debug, PartialEq)]
+enum Event {
+    Cleared,
+    DataSwapped(usize, usize),
+    Inserted(i32, usize),
+    Moved(i32, usize, usize),
+    Removed(i32, usize),
+}
+
+/// Returns a list holding `data`, and the log of `Event`s subsequently observed on it.
+fn observed_list_of(data: &[i32]) -> (DoublyLinkedList<i32>, Rc<RefCell<Vec<Event>>>) {
+    let mut list = DoublyLinkedList::new();
+    data.iter().for_each(|&datum| { list.push_back(datum); });
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&log);
+    list.observe(move |mutation| sink.borrow_mut().push(match mutation {
+        Mutation::Cleared => Event::Cleared,
+        Mutation::DataSwapped { a_position, b_position, .. } => Event::DataSwapped(*a_position, *b_position),
+        Mutation::Inserted { node, position } => Event::Inserted(node.borrow().data, *position),
+        Mutation::Moved { node, from, to } => Event::Moved(node.borrow().data, *from, *to),
+        Mutation::Removed { data, position } => Event::Removed(**data, *position),
+    }));
+    (list, log)
+}
+
+#[test]
+fn observers_are_notified_of_insertions_with_their_positions() {
+    // given an observed list
+    let (mut list, log) = observed_list_of(&[2, 4]);
+
+    // when data is inserted by each insertion method
+    list.push_front(1).push_back(6);
+    let four = list.iter().nth(2).unwrap();
+    list.insert_before(four.clone(), 3).unwrap().insert_after(four, 5).unwrap();
+    list.insert_sorted(0, SearchFrom::Front);
+
+    // then each insertion is reported with its position
+    assert_eq!(*log.borrow(), vec![Event::Inserted(1, 0),
+                                   Event::Inserted(6, 3),
+                                   Event::Inserted(3, 2),
+                                   Event::Inserted(5, 4),
+                                   Event::Inserted(0, 0)]);
+}
+
+#[test]
+fn observers_are_notified_of_removals_with_the_removed_data() {
+    // given an observed list
+    let (mut list, log) = observed_list_of(&[1, 2, 3, 4]);
+
+    // when data is removed by each removal method
+    list.pop_front().unwrap();
+    list.pop_back().unwrap();
+    let node = list.iter().nth(1).unwrap();
+    list.remove(node).unwrap();
+
+    // then each removal is reported with the removed data and its former position
+    assert_eq!(*log.borrow(), vec![Event::Removed(1, 0), Event::Removed(4, 2), Event::Removed(3, 1)]);
+}
+
+#[test]
+fn observers_are_notified_of_moves_and_swaps() {
+    // given an observed list
+    let (mut list, log) = observed_list_of(&[1, 2, 3]);
+    let (head, tail) = (list.iter().next().unwrap(), list.iter().nth(2).unwrap());
+
+    // when nodes are moved and swapped
+    list.move_to_back(&head).unwrap();
+    list.swap_nodes(&head, &tail).unwrap();
+    list.swap_data(&head, &tail).unwrap();
+
+    // then each move and swap is reported
+    assert_eq!(*log.borrow(), vec![Event::Moved(1, 0, 2),
+                                   Event::Moved(3, 1, 2),
+                                   Event::Moved(1, 2, 1),
+                                   Event::DataSwapped(1, 2)]);
+}
+
+#[test]
+fn observers_are_notified_of_a_clear_once() {
+    // given an observed list
+    let (mut list, log) = observed_list_of(&[1, 2, 3]);
+
+    // when it is cleared
+    list.clear();
+
+    // then the clear alone is reported
+    assert!(list.is_empty());
+    assert_eq!(*log.borrow(), vec![Event::Cleared]);
+}
+
+#[test]
+fn observers_are_notified_of_merged_nodes() {
+    // given an observed list
+    let (mut list, log) = observed_list_of(&[1, 4]);
+    let mut other = DoublyLinkedList::new();
+    other.push_back(2).push_back(5);
+
+    // when another list is merged into it
+    list.merge(other);
+
+    // then each merged node is reported at its final position
+    assert_eq!(*log.borrow(), vec![Event::Inserted(2, 1), Event::Inserted(5, 3)]);
+}
+
+#[test]
+fn unobserved_lists_stop_notifying() {
+    // given an observed list
+    let mut list = DoublyLinkedList::new();
+    let count = Rc::new(RefCell::new(0));
+    let sink = Rc::clone(&count);
+    let id = list.observe(move |_| *sink.borrow_mut() += 1);
+    list.push_back(1);
+
+    // when the observer is deregistered
+    let result = (list.unobserve(id), list.unobserve(id));
+
+    // then only the first deregistration succeeds
+    assert_eq!(result, (true, false));
+
+    // and subsequent edits are not reported
+    list.push_back(2);
+    assert_eq!(*count.borrow(), 1);
+}
+
+#[test]
+fn observers_cannot_re_enter_a_shared_list() {
+    // given a shared list with an observer which attempts to edit the list when notified
+    let list = Rc::new(RefCell::new(DoublyLinkedList::new()));
+    let reentered = Rc::new(RefCell::new(None));
+    let (weak_list, sink) = (Rc::downgrade(&list), Rc::clone(&reentered));
+    list.borrow_mut().observe(move |_| {
+        let shared = weak_list.upgrade().unwrap();
+        *sink.borrow_mut() = Some(shared.try_borrow_mut().map(|mut list| { list.push_back(0); }).is_ok());
+    });
+
+    // when the list is edited
+    list.borrow_mut().push_back(1);
+
+    // then the observer's attempt to re-enter the list was refused, and the list is intact
+    assert_eq!(*reentered.borrow(), Some(false));
+    assert_eq!(list.borrow().iter().map(|link| link.borrow().data).collect::<Vec<_>>(), vec![1]);
+}
+
+#[test]
+fn observers_see_undo_and_rollback_edits() {
+    // given an observed, journaled list
+    let (mut list, log) = observed_list_of(&[1]);
+    list.enable_journal().push_back(2);
+
+    // when the edit is undone, and a failing transaction edits the list
+    list.undo().unwrap();
+    let _ = list.transaction(|tx| tx.pop_front().and(Err::<(), _>(Error::EmptyList { op: Operation::PopFront })));
+
+    // then every edit is reported, so secondary indexes remain in sync
+    assert_eq!(*log.borrow(), vec![Event::Inserted(2, 1),
+                                   Event::Removed(2, 1),
+                                   Event::Removed(1, 0),
+                                   Event::Inserted(1, 0)]);
+}
@@ -0,0 +1,104 @@
+use crate::Node;
+use alloc::{
+    rc::Rc,
+    vec::Vec,
+};
+use core::{
+    cell::RefCell,
+    result::Result as StdResult,
+};
+
+type Allocation<T> = Rc<RefCell<Node<T>>>;
+
+/// Counts of the `Node` allocations made by a `DoublyLinkedList`, for verifying the effectiveness of its node pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AllocationStats {
+    /// `Node`s allocated from the heap (including those allocated by `reserve()`).
+    pub allocated: usize,
+    /// `Node`s served from the pool instead of the heap.
+    pub reused: usize,
+    /// `Node` allocations currently held in the pool awaiting reuse.
+    pub pooled: usize,
+}
+
+/// A free list of `Node` allocations released by a `DoublyLinkedList`.  Safe Rust offers no way to hold an allocation
+/// without a valid `T` inside it, so each pooled `Node` holds a placeholder value produced by `placeholder`.
+#[derive(Debug)]
+pub(super) struct Pool<T> {
+    free: Vec<Allocation<T>>,
+    capacity: usize,
+    placeholder: fn() -> T,
+}
+
+impl<T> Pool<T> {
+    pub(super) fn new(capacity: usize, placeholder: fn() -> T) -> Self {
+        Self {
+            free: Vec::new(),
+            capacity,
+            placeholder,
+        }
+    }
+
+    pub(super) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Adds a freshly allocated placeholder `Node` to the pool, if there is room.  Returns `true` if it was added.
+    pub(super) fn fill(&mut self) -> bool {
+        match self.free.len() < self.capacity {
+            true => {
+                self.free.push(Rc::new(RefCell::new(Node::new((self.placeholder)()))));
+                true
+            },
+            false => false,
+        }
+    }
+
+    /// Moves `data` out of the uniquely-owned `allocation` and retains the allocation for reuse, if there is room.
+    /// Otherwise, returns `allocation` unchanged.
+    pub(super) fn recycle(&mut self, mut allocation: Allocation<T>) -> StdResult<T, Allocation<T>> {
+        match self.free.len() < self.capacity {
+            true => match Rc::get_mut(&mut allocation) {
+                Some(cell) => {
+                    let node = cell.get_mut();
+                    node.detach();
+                    let data = core::mem::replace(&mut node.data, (self.placeholder)());
+                    self.free.push(allocation);
+                    Ok(data)
+                },
+                None => Err(allocation),
+            },
+            false => Err(allocation),
+        }
+    }
+
+    /// Changes the pool's capacity, releasing pooled allocations in excess of it.
+    pub(super) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.free.truncate(capacity);
+    }
+
+    /// Releases every pooled allocation.
+    pub(super) fn shrink_to_fit(&mut self) {
+        self.free.clear();
+        self.free.shrink_to_fit();
+    }
+
+    /// Places `data` into a pooled allocation, or returns `data` if the pool is empty.
+    pub(super) fn take(&mut self, data: T) -> StdResult<Allocation<T>, T> {
+        match self.free.pop() {
+            Some(mut allocation) => match Rc::get_mut(&mut allocation) {
+                Some(cell) => {
+                    cell.get_mut().data = data;
+                    Ok(allocation)
+                },
+                None => Err(data),
+            },
+            None => Err(data),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    NodeLink,
+    WeakLink,
+};
+
+/// `Range` iterates over a contiguous, validated sub-sequence of a `DoublyLinkedList`'s `Node`s, from either end.  Its
+/// length is fixed on creation, so if the list is structurally modified during iteration, iteration still stops after
+/// that many `Node`s (or sooner, should a `Node` no longer have a neighbour to advance to).
+#[derive(Debug)]
+pub struct Range<T> {
+    front: Option<NodeLink<T>>,
+    back: Option<NodeLink<T>>,
+    len: usize,
+}
+
+impl<T> Range<T> {
+    /// Creates a `Range` of the `len` `Node`s from `front` through `back`, inclusive.
+    pub(super) fn new(front: Option<NodeLink<T>>, back: Option<NodeLink<T>>, len: usize) -> Self {
+        match len {
+            0 => Self { front: None, back: None, len },
+            _ => Self { front, back, len },
+        }
+    }
+
+    /// Yields the `Node` at the front (or back) of the `Range`, replacing it with its neighbour (per `advance`) unless
+    /// the `Range` is thereby exhausted.
+    fn take_from<F>(&mut self, advance: F, front: bool) -> Option<NodeLink<T>>
+                   where F: FnOnce(&NodeLink<T>) -> Option<NodeLink<T>> {
+        self.len = self.len.checked_sub(1)?;
+        let end = match front {
+            true => &mut self.front,
+            false => &mut self.back,
+        };
+        let node = end.take();
+        match (self.len, &node) {
+            (0, _) | (_, None) => {
+                self.front = None;
+                self.back = None;
+                self.len = 0;
+            },
+            (_, Some(node)) => *end = advance(node),
+        }
+        node
+    }
+}
+
+impl<T> Iterator for Range<T> {
+    type Item = NodeLink<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.take_from(|node| node.borrow().next.clone(), true)
+    }
+
+    /// At most the `Range`'s remaining length; fewer `Node`s are yielded if the list is modified during iteration.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Range<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.take_from(|node| node.borrow().prev.as_ref().and_then(WeakLink::to_strong), false)
+    }
+}
@@ -0,0 +1,158 @@
+#![allow(clippy::unwrap_used)]
+use crate::{
+    doubly_linked_list::test_helpers::list_of,
+    DoublyLinkedList,
+    Error,
+    NodeLink,
+    Operation,
+};
+
+fn node_at(list: &DoublyLinkedList<i32>, index: usize) -> NodeLink<i32> {
+    list.iter().nth(index).unwrap()
+}
+
+fn data<I: Iterator<Item = NodeLink<i32>>>(iter: I) -> Vec<i32> {
+    iter.map(|link| link.borrow().data).collect()
+}
+
+#[test]
+fn range_yields_nodes_from_the_start_up_to_but_excluding_the_end() {
+    // given a list and two nodes within it
+    let list = list_of(&[1, 2, 3, 4, 5]);
+    let (from, to) = (node_at(&list, 1), node_at(&list, 3));
+
+    // when a range between them is iterated
+    let result = list.range(&from, &to).unwrap();
+
+    // then the nodes from the start up to (but excluding) the end are yielded
+    assert_eq!(result.size_hint(), (0, Some(2)));
+    assert_eq!(data(result), vec![2, 3]);
+}
+
+#[test]
+fn range_inclusive_yields_nodes_through_the_end() {
+    // given a list and two nodes within it
+    let list = list_of(&[1, 2, 3, 4, 5]);
+    let (from, to) = (node_at(&list, 1), node_at(&list, 3));
+
+    // when an inclusive range between them is iterated
+    let result = list.range_inclusive(&from, &to).unwrap();
+
+    // then the nodes from the start through the end are yielded
+    assert_eq!(data(result), vec![2, 3, 4]);
+}
+
+#[test]
+fn ranges_iterate_backward() {
+    // given a list and two nodes within it
+    let list = list_of(&[1, 2, 3, 4, 5]);
+    let (from, to) = (node_at(&list, 0), node_at(&list, 4));
+
+    // when ranges between them are iterated in reverse
+    let exclusive = data(list.range(&from, &to).unwrap().rev());
+    let inclusive = data(list.range_inclusive(&from, &to).unwrap().rev());
+
+    // then the nodes are yielded from the end toward the start
+    assert_eq!(exclusive, vec![4, 3, 2, 1]);
+    assert_eq!(inclusive, vec![5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn range_ends_meet_without_overlapping() {
+    // given an inclusive range over three nodes
+    let list = list_of(&[1, 2, 3]);
+    let mut sut = list.range_inclusive(&node_at(&list, 0), &node_at(&list, 2)).unwrap();
+
+    // when it is consumed from alternating ends
+    let result = (sut.next(), sut.next_back(), sut.next_back(), sut.next(), sut.next_back());
+
+    // then each node is yielded exactly once
+    assert_eq!(result.0.map(|link| link.borrow().data), Some(1));
+    assert_eq!(result.1.map(|link| link.borrow().data), Some(3));
+    assert_eq!(result.2.map(|link| link.borrow().data), Some(2));
+    assert!(result.3.is_none());
+    assert!(result.4.is_none());
+}
+
+#[test]
+fn range_from_a_node_to_itself_is_empty_unless_inclusive() {
+    // given a node
+    let list = list_of(&[1, 2]);
+    let node = node_at(&list, 1);
+
+    // when ranges from the node to itself are created
+    let exclusive = list.range(&node, &node).unwrap();
+    let inclusive = list.range_inclusive(&node, &node).unwrap();
+
+    // then only the inclusive range yields the node
+    assert_eq!(data(exclusive), Vec::<i32>::new());
+    assert_eq!(data(inclusive), vec![2]);
+}
+
+#[test]
+fn range_to_a_preceding_node_is_rejected() {
+    // given two nodes in reverse order
+    let list = list_of(&[1, 2, 3]);
+    let (from, to) = (node_at(&list, 2), node_at(&list, 0));
+
+    // when a range between them is requested
+    let result = (list.range(&from, &to).map(|_| ()), list.range_inclusive(&from, &to).map(|_| ()));
+
+    // then the end is reported as unreachable
+    assert_eq!(result, (Err(Error::NodeNotReachable { op: Operation::Range }),
+                        Err(Error::NodeNotReachable { op: Operation::RangeInclusive })));
+}
+
+#[test]
+fn range_over_a_foreign_node_is_rejected() {
+    // given a node from another list
+    let list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+
+    // when a range ending at the foreign node is requested
+    let result = list.range(&node_at(&list, 0), &node_at(&other, 0)).map(|_| ());
+
+    // then the node is reported as not belonging to the list
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::Range }));
+}
+
+#[test]
+fn iter_from_yields_nodes_from_the_given_node_to_the_tail() {
+    // given a list and a node within it
+    let list = list_of(&[1, 2, 3, 4]);
+    let node = node_at(&list, 2);
+
+    // when iteration begins at the node
+    let result = data(list.iter_from(&node).unwrap());
+
+    // then the node and its successors are yielded
+    assert_eq!(result, vec![3, 4]);
+}
+
+#[test]
+fn iter_back_from_yields_nodes_from_the_given_node_to_the_head() {
+    // given a list and a node within it
+    let list = list_of(&[1, 2, 3, 4]);
+    let node = node_at(&list, 2);
+
+    // when backward iteration begins at the node
+    let result = data(list.iter_back_from(&node).unwrap());
+
+    // then the node and its predecessors are yielded
+    assert_eq!(result, vec![3, 2, 1]);
+}
+
+#[test]
+fn iter_from_a_foreign_node_is_rejected() {
+    // given an empty list, and a node from another list
+    let list = DoublyLinkedList::new();
+    let other = list_of(&[1]);
+    let node = node_at(&other, 0);
+
+    // when iteration is requested from the foreign node
+    let result = (list.iter_from(&node).map(|_| ()), list.iter_back_from(&node).map(|_| ()));
+
+    // then the node is reported as not belonging to the list
+    assert_eq!(result, (Err(Error::NodeNotInList { op: Operation::IterFrom }),
+                        Err(Error::NodeNotInList { op: Operation::IterBackFrom })));
+}
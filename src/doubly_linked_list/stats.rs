@@ -0,0 +1,80 @@
+use super::{
+    pool::Pool,
+    DoublyLinkedList,
+};
+use crate::Node;
+use alloc::{
+    rc::Rc,
+    vec::Vec,
+};
+use core::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    mem::size_of,
+};
+
+/// The strong and weak reference counts of a single `Node`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RefCounts {
+    pub strong: usize,
+    pub weak: usize,
+}
+
+/// Memory and reference-count diagnostics for a `DoublyLinkedList`, for tracking down the outstanding references
+/// behind an `Error::ExistingLiveReferences`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ListStats {
+    /// `Node`s in the list.
+    pub len: usize,
+    /// Estimated heap bytes held by the list: its `Node` allocations (including pooled allocations) and bookkeeping.
+    /// Excludes any heap memory owned by the `data` itself.
+    pub heap_bytes: usize,
+    /// Reference counts of each `Node`, in list order.  A `Node` referenced only by the list has one strong reference
+    /// (from `head` or its predecessor) and one weak reference (from `tail` or its successor).
+    pub ref_counts: Vec<RefCounts>,
+    /// Positions of the `Node`s with strong references held outside the list (e.g. by outstanding `NodeLink`s), in list
+    /// order.  Such `Node`s cannot be popped or removed.
+    pub externally_held: Vec<usize>,
+}
+
+impl ListStats {
+    /// Number of `Node`s with strong references held outside the list.
+    pub fn externally_held_count(&self) -> usize {
+        self.externally_held.len()
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Gathers memory and reference-count diagnostics for the list.  O(n).
+    pub fn stats(&self) -> ListStats {
+        // An `Rc` allocation holds its strong and weak counts alongside its value
+        let rc_bytes = |value_bytes: usize| value_bytes.saturating_add(size_of::<usize>().saturating_mul(2));
+        let node_bytes = rc_bytes(size_of::<RefCell<Node<T>>>());
+
+        // Each yielded `NodeLink` accounts for one strong reference which is not the list's
+        let ref_counts = self.iter()
+                             .map(|node| RefCounts {
+                                 strong: Rc::strong_count(&node).saturating_sub(1),
+                                 weak: Rc::weak_count(&node),
+                             })
+                             .collect::<Vec<_>>();
+        let externally_held = ref_counts.iter()
+                                        .enumerate()
+                                        .filter(|(_, counts)| counts.strong > 1)
+                                        .map(|(position, _)| position)
+                                        .collect();
+        let pooled = self.pool.as_ref().map_or(0, Pool::len);
+        let heap_bytes = node_bytes.saturating_mul(ref_counts.len().saturating_add(pooled))
+                                   .saturating_add(size_of::<Rc<()>>().saturating_mul(pooled))
+                                   .saturating_add(rc_bytes(size_of::<()>()))
+                                   .saturating_add(rc_bytes(size_of::<Cell<usize>>()));
+        ListStats {
+            len: ref_counts.len(),
+            heap_bytes,
+            ref_counts,
+            externally_held,
+        }
+    }
+}
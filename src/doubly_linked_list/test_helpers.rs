@@ -0,0 +1,30 @@
+use super::DoublyLinkedList;
+use crate::WeakLink;
+use alloc::vec::Vec;
+
+/// Builds a list containing `data`, in order.
+pub(super) fn list_of<T: Clone>(data: &[T]) -> DoublyLinkedList<T> {
+    let mut list = DoublyLinkedList::new();
+    data.iter().cloned().for_each(|datum| { list.push_back(datum); });
+    list
+}
+
+/// Collects the list's data by following `next` links from `head`.
+pub(super) fn forward<T: Clone>(list: &DoublyLinkedList<T>) -> Vec<T> {
+    list.iter()
+        .map(|link| link.borrow().data.clone())
+        .collect()
+}
+
+/// Collects the list's data by following `prev` links from `tail`, reversed into head-to-tail order.  Comparing this
+/// with `forward()` verifies that `prev`, `tail` and `head` links are consistent with `next` links.
+pub(super) fn backward<T: Clone>(list: &DoublyLinkedList<T>) -> Vec<T> {
+    let mut data = Vec::new();
+    let mut link_opt = list.tail.as_ref().and_then(WeakLink::to_strong);
+    while let Some(link) = link_opt {
+        data.push(link.borrow().data.clone());
+        link_opt = link.borrow().prev.as_ref().and_then(WeakLink::to_strong);
+    }
+    data.reverse();
+    data
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod unit_tests;
+use super::{
+    journal::Savepoint,
+    DoublyLinkedList,
+    SearchFrom,
+};
+use crate::{
+    NodeLink,
+    Result,
+};
+use core::{
+    cmp::Ordering,
+    ops::Deref,
+};
+
+/// `Transaction` exposes the mutating methods of a `DoublyLinkedList` within `DoublyLinkedList::transaction()`.  Each
+/// edit takes effect immediately, but is reverted if the transaction fails.  Dereferences to the list for read-only
+/// access.
+#[derive(Debug)]
+pub struct Transaction<'a, T> {
+    list: &'a mut DoublyLinkedList<T>,
+    savepoint: Option<Savepoint<T>>,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Runs `body` as an atomic batch of edits.  If `body` returns an error or panics, every edit made through its
+    /// `Transaction` is rolled back, leaving the list (and its journal, if enabled) as it was; the error (or panic) is
+    /// then propagated.  Rollback restores the list's structure and data, but `Node`s removed within the transaction
+    /// are reinstated as new `Node`s, and `Node`s inserted within it are unlinked even if `NodeLink`s to them remain.
+    pub fn transaction<F, R>(&mut self, body: F) -> Result<R> where T: Clone,
+                                                                   F: FnOnce(&mut Transaction<'_, T>) -> Result<R> {
+        let mut tx = Transaction {
+            savepoint: Some(self.savepoint()),
+            list: self,
+        };
+        let result = body(&mut tx);
+        if result.is_ok() {
+            if let Some(savepoint) = tx.savepoint.take() {
+                tx.list.commit(savepoint);
+            }
+        }
+        // Otherwise, `tx` rolls back when dropped (as it also does if `body` panics)
+        result
+    }
+}
+
+impl<T> Transaction<'_, T> {
+    /// See `DoublyLinkedList::clear()`.
+    pub fn clear(&mut self) -> &mut Self {
+        self.list.clear();
+        self
+    }
+
+    /// See `DoublyLinkedList::insert_after()`.
+    pub fn insert_after(&mut self, curr: NodeLink<T>, data: T) -> Result<&mut Self> {
+        self.list.insert_after(curr, data)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::insert_before()`.
+    pub fn insert_before(&mut self, curr: NodeLink<T>, data: T) -> Result<&mut Self> {
+        self.list.insert_before(curr, data)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::insert_sorted()`.
+    pub fn insert_sorted(&mut self, data: T, hint: SearchFrom) -> NodeLink<T> where T: PartialOrd {
+        self.list.insert_sorted(data, hint)
+    }
+
+    /// See `DoublyLinkedList::insert_sorted_by()`.
+    pub fn insert_sorted_by<F>(&mut self, data: T, hint: SearchFrom, cmp: F) -> NodeLink<T>
+                              where F: FnMut(&T, &T) -> Ordering {
+        self.list.insert_sorted_by(data, hint, cmp)
+    }
+
+    /// See `DoublyLinkedList::merge()`.
+    pub fn merge(&mut self, other: DoublyLinkedList<T>) -> &mut Self where T: PartialOrd {
+        self.list.merge(other);
+        self
+    }
+
+    /// See `DoublyLinkedList::merge_by()`.
+    pub fn merge_by<F>(&mut self, other: DoublyLinkedList<T>, cmp: F) -> &mut Self where F: FnMut(&T, &T) -> Ordering {
+        self.list.merge_by(other, cmp);
+        self
+    }
+
+    /// See `DoublyLinkedList::move_after()`.
+    pub fn move_after(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.move_after(node, anchor)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::move_before()`.
+    pub fn move_before(&mut self, node: &NodeLink<T>, anchor: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.move_before(node, anchor)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::move_to_back()`.
+    pub fn move_to_back(&mut self, node: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.move_to_back(node)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::move_to_front()`.
+    pub fn move_to_front(&mut self, node: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.move_to_front(node)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::pop_back()`.
+    pub fn pop_back(&mut self) -> Result<T> {
+        self.list.pop_back()
+    }
+
+    /// See `DoublyLinkedList::pop_front()`.
+    pub fn pop_front(&mut self) -> Result<T> {
+        self.list.pop_front()
+    }
+
+    /// See `DoublyLinkedList::push_back()`.
+    pub fn push_back(&mut self, data: T) -> &mut Self {
+        self.list.push_back(data);
+        self
+    }
+
+    /// See `DoublyLinkedList::push_front()`.
+    pub fn push_front(&mut self, data: T) -> &mut Self {
+        self.list.push_front(data);
+        self
+    }
+
+    /// See `DoublyLinkedList::remove()`.
+    pub fn remove(&mut self, node: NodeLink<T>) -> Result<T> {
+        self.list.remove(node)
+    }
+
+    /// See `DoublyLinkedList::swap_data()`.
+    pub fn swap_data(&mut self, a: &NodeLink<T>, b: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.swap_data(a, b)?;
+        Ok(self)
+    }
+
+    /// See `DoublyLinkedList::swap_nodes()`.
+    pub fn swap_nodes(&mut self, a: &NodeLink<T>, b: &NodeLink<T>) -> Result<&mut Self> {
+        self.list.swap_nodes(a, b)?;
+        Ok(self)
+    }
+}
+
+impl<T> Deref for Transaction<'_, T> {
+    type Target = DoublyLinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.list
+    }
+}
+
+/// Rolls back a `Transaction` which was not committed, whether because its body failed or panicked.
+impl<T> Drop for Transaction<'_, T> {
+    fn drop(&mut self) {
+        if let Some(savepoint) = self.savepoint.take() {
+            self.list.rollback(savepoint);
+        }
+    }
+}
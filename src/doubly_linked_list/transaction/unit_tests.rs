@@ -0,0 +1,132 @@
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+use crate::{
+    doubly_linked_list::test_helpers::{
+        forward,
+        list_of,
+    },
+    Error,
+    Operation,
+    SearchFrom,
+};
+use std::panic::{
+    self,
+    AssertUnwindSafe,
+};
+
+#[test]
+fn transaction_which_succeeds_retains_its_edits() {
+    // given a list
+    let mut list = list_of(&[1, 2, 3]);
+
+    // when a transaction edits it and succeeds
+    let result = list.transaction(|tx| {
+        let head = tx.iter().next().unwrap();
+        tx.remove(head)?;
+        tx.push_back(4).push_front(0);
+        Ok(tx.len())
+    });
+
+    // then the edits are retained
+    assert_eq!(result, Ok(4));
+    assert_eq!(forward(&list), vec![0, 2, 3, 4]);
+
+    // and journaling, which the transaction enabled, is disabled again
+    assert!(!list.is_journaling());
+}
+
+#[test]
+fn transaction_which_fails_rolls_back_every_edit() {
+    // given a list, and a `NodeLink` which will block the transaction's last removal
+    let mut list = list_of(&[1, 2, 3, 4, 5]);
+    let blocker = list.iter().nth(4).unwrap();
+
+    // when a transaction removes, inserts, moves and swaps nodes before a removal fails
+    let result = list.transaction(|tx| {
+        let (first, second) = (tx.iter().next().unwrap(), tx.iter().nth(1).unwrap());
+        let (third, fourth) = (tx.iter().nth(2).unwrap(), tx.iter().nth(3).unwrap());
+        tx.remove(second)?;
+        tx.insert_after(third.clone(), 30)?.insert_before(first.clone(), 10)?;
+        tx.move_to_back(&first)?.swap_nodes(&third, &fourth)?.swap_data(&third, &fourth)?;
+        tx.insert_sorted(0, SearchFrom::Front);
+        tx.pop_front()?;
+        let last = tx.iter().last().unwrap();
+        tx.remove(last)
+    });
+
+    // then the failure is reported
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::Remove, .. })));
+
+    // and the list is exactly as it was
+    assert_eq!(forward(&list), vec![1, 2, 3, 4, 5]);
+    let nodes = list.iter().collect::<Vec<_>>();
+    assert!(nodes.windows(2)
+                    .all(|pair| pair[1].borrow().prev.as_ref().and_then(|weak| weak.to_strong()).as_ref() ==
+                                Some(&pair[0])));
+    assert!(list.stats().ref_counts.iter().all(|counts| counts.weak == 1));
+    drop(blocker);
+}
+
+#[test]
+fn transaction_which_panics_rolls_back_every_edit() {
+    // given a list
+    let mut list = list_of(&[1, 2]);
+
+    // when a transaction edits it, then panics
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.transaction(|tx| -> crate::Result<()> {
+            tx.push_back(3).pop_front()?;
+            panic!("transaction body failed");
+        })
+    }));
+
+    // then the panic is propagated
+    assert!(result.is_err());
+
+    // and the list is as it was
+    assert_eq!(forward(&list), vec![1, 2]);
+}
+
+#[test]
+fn transaction_rollback_unlinks_inserted_nodes_which_are_still_referenced() {
+    // given a list
+    let mut list = list_of(&[1]);
+    let mut leaked = None;
+
+    // when a failing transaction inserts a node, and a `NodeLink` to it outlives the transaction
+    let result = list.transaction(|tx| {
+        leaked = Some(tx.insert_sorted(2, SearchFrom::Back));
+        tx.pop_back().and(Err::<(), _>(Error::EmptyList { op: Operation::PopBack }))
+    });
+
+    // then the transaction fails, and the inserted node is nonetheless unlinked
+    assert!(result.is_err());
+    assert_eq!(forward(&list), vec![1]);
+    assert_eq!(list.remove(leaked.unwrap()), Err(Error::NodeNotInList { op: Operation::Remove }));
+}
+
+#[test]
+fn transaction_on_a_journaled_list_preserves_its_history() {
+    // given a journaled list with an edit to undo and another to redo
+    let mut list = list_of(&[]);
+    list.enable_journal().push_back(1).push_back(2);
+    list.undo().unwrap();
+
+    // when a transaction fails
+    let result = list.transaction(|tx| {
+        tx.push_back(3);
+        tx.pop_front().and(Err::<(), _>(Error::EmptyList { op: Operation::PopFront }))
+    });
+
+    // then the list and its history are as they were
+    assert!(result.is_err());
+    assert_eq!(forward(&list), vec![1]);
+    assert!(list.is_journaling());
+    assert_eq!(list.redo(), Ok(true));
+    assert_eq!(forward(&list), vec![1, 2]);
+
+    // and a transaction which succeeds can be undone
+    list.checkpoint();
+    list.transaction(|tx| { tx.push_front(0).push_back(3); Ok(()) }).unwrap();
+    list.undo_to_checkpoint().unwrap();
+    assert_eq!(forward(&list), vec![1, 2]);
+}
@@ -1,10 +1,30 @@
+#![allow(clippy::unwrap_used)]
 use super::*;
+use super::test_helpers::{
+    backward,
+    forward,
+    list_of,
+};
+mod clear;
 mod default;
+mod drop;
 mod insert_after;
 mod insert_before;
+mod insert_sorted;
 mod iter;
+mod merge;
+mod move_after;
+mod move_before;
+mod move_to_back;
+mod move_to_front;
 mod new;
+mod pool;
 mod push_back;
 mod push_front;
 mod pop_front;
 mod pop_back;
+mod remove;
+mod stats;
+mod swap_data;
+mod swap_nodes;
+mod to_dot;
@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn clear_removes_every_node() {
+    // given a non-empty list
+    let mut list = list_of(&[1, 2, 3]);
+
+    // when it is cleared
+    list.clear();
+
+    // then it is empty
+    assert!(list.is_empty());
+    assert_eq!(backward(&list), Vec::<i32>::new());
+}
+
+#[test]
+fn clear_unlinks_nodes_with_live_references() {
+    // given a list with an outstanding reference to one of its nodes
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(1).unwrap();
+
+    // when it is cleared
+    list.clear();
+
+    // then it is empty
+    assert!(list.is_empty());
+
+    // and the referenced node no longer belongs to the list
+    assert_eq!(node.borrow().data, 2);
+    assert_eq!(list.remove(node), Err(Error::NodeNotInList { op: Operation::Remove }));
+}
+
+#[test]
+fn clear_of_a_journaled_list_can_be_undone() {
+    // given a journaled list
+    let mut list = list_of(&[1, 2, 3]);
+    list.enable_journal().checkpoint();
+
+    // when it is cleared and the clear undone
+    list.clear();
+    let result = list.undo_to_checkpoint();
+
+    // then the list is restored
+    assert_eq!(result, Ok(true));
+    assert_eq!(forward(&list), vec![1, 2, 3]);
+    assert_eq!(backward(&list), vec![1, 2, 3]);
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn dropping_a_long_list_does_not_overflow_the_stack() {
+    // given a long list
+    let mut list = DoublyLinkedList::new();
+    (0..200_000).for_each(|datum| { list.push_back(datum); });
+
+    // when the list is dropped
+    drop(list);
+
+    // then dropping completes without recursing once per `Node`
+}
+
+#[test]
+fn dropping_a_list_leaves_a_held_node_linked_to_its_successors() {
+    // given a list and a live reference to its second node
+    let list = list_of(&[1, 2, 3]);
+    let held = list.iter().nth(1).unwrap();
+
+    // when the list is dropped
+    drop(list);
+
+    // then the held node still leads to its successor
+    let next = held.borrow().next.clone().unwrap();
+    assert_eq!(next.borrow().data, 3);
+}
@@ -1,5 +1,5 @@
-#![allow(clippy::option_unwrap_used)]
-use crate::DoublyLinkedList;
+#![allow(clippy::unwrap_used)]
+use super::*;
 
 #[test]
 fn insert_after_on_a_list_with_one_node_yields_a_list_of_expected_length_and_order() {
@@ -14,10 +14,10 @@ fn insert_after_on_a_list_with_one_node_yields_a_list_of_expected_length_and_ord
     let node_link = iter.next().unwrap();
 
     // when insert_after() is called
-    list.insert_after(node_link, sample_data_2.clone());
+    list.insert_after(node_link, sample_data_2.clone()).unwrap();
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 2);
@@ -46,10 +46,10 @@ fn insert_after_at_1st_node_on_a_list_with_two_nodes_yields_a_list_of_expected_l
     let node_link = iter.next().unwrap();
 
     // when insert_after() is called
-    list.insert_after(node_link, sample_data_3.clone());
+    list.insert_after(node_link, sample_data_3.clone()).unwrap();
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 3);
@@ -63,3 +63,21 @@ fn insert_after_at_1st_node_on_a_list_with_two_nodes_yields_a_list_of_expected_l
     // and after reading the expected nodes, there are no more nodes
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn insert_after_a_node_of_another_list_yields_an_error() {
+    // given two lists and a handle to the head of the other list
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let foreign = other.iter().next().unwrap();
+
+    // when insert_after() is called with the foreign node
+    let result = list.insert_after(foreign, 4).map(|_| ());
+
+    // then the insertion is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::InsertAfter }));
+
+    // and both lists are left unchanged and consistent
+    assert_eq!((list.len(), forward(&list), backward(&list)), (2, vec![1, 2], vec![1, 2]));
+    assert_eq!((other.len(), forward(&other), backward(&other)), (1, vec![3], vec![3]));
+}
@@ -1,5 +1,5 @@
-#![allow(clippy::option_unwrap_used)]
-use crate::DoublyLinkedList;
+#![allow(clippy::unwrap_used)]
+use super::*;
 
 #[test]
 fn insert_before_on_a_list_with_one_node_yields_a_list_of_expected_length_and_order() {
@@ -14,10 +14,10 @@ fn insert_before_on_a_list_with_one_node_yields_a_list_of_expected_length_and_or
     let node_link = iter.next().unwrap();
 
     // when insert_before() is called
-    list.insert_before(node_link, sample_data_2.clone());
+    list.insert_before(node_link, sample_data_2.clone()).unwrap();
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 2);
@@ -47,10 +47,10 @@ fn insert_before_at_2nd_node_on_a_list_with_two_nodes_yields_a_list_of_expected_
     let node_link = iter.next().unwrap();
 
     // when insert_before() is called
-    list.insert_before(node_link, sample_data_3.clone());
+    list.insert_before(node_link, sample_data_3.clone()).unwrap();
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 3);
@@ -64,3 +64,21 @@ fn insert_before_at_2nd_node_on_a_list_with_two_nodes_yields_a_list_of_expected_
     // and after reading the expected nodes, there are no more nodes
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn insert_before_a_node_of_another_list_yields_an_error() {
+    // given two lists and a handle to the head of the other list
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let foreign = other.iter().next().unwrap();
+
+    // when insert_before() is called with the foreign node
+    let result = list.insert_before(foreign, 4).map(|_| ());
+
+    // then the insertion is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::InsertBefore }));
+
+    // and both lists are left unchanged and consistent
+    assert_eq!((list.len(), forward(&list), backward(&list)), (2, vec![1, 2], vec![1, 2]));
+    assert_eq!((other.len(), forward(&other), backward(&other)), (1, vec![3], vec![3]));
+}
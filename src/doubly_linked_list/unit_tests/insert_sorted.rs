@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn insert_sorted_positions_data_after_all_data_not_greater_than_it() {
+    // given an ascending list
+    let mut list = list_of(&[1, 3, 3, 5]);
+
+    // when data is inserted in order, searching from the front and from the back
+    list.insert_sorted(3, SearchFrom::Front);
+    list.insert_sorted(4, SearchFrom::Back);
+    list.insert_sorted(0, SearchFrom::Back);
+    list.insert_sorted(6, SearchFrom::Front);
+
+    // then the list remains ascending in both directions
+    assert_eq!(forward(&list), vec![0, 1, 3, 3, 3, 4, 5, 6]);
+    assert_eq!(backward(&list), vec![0, 1, 3, 3, 3, 4, 5, 6]);
+}
+
+#[test]
+fn insert_sorted_returns_a_handle_to_the_inserted_node() {
+    // given an ascending list
+    let mut list = list_of(&[1, 3]);
+
+    // when data is inserted in order
+    let result = list.insert_sorted(2, SearchFrom::Front);
+
+    // then the returned handle refers to the inserted node
+    assert!(Rc::ptr_eq(&result, &list.iter().nth(1).unwrap()));
+}
+
+#[test]
+fn insert_sorted_treats_incomparable_data_as_equal() {
+    // given an ascending list of floats
+    let mut list = list_of(&[1.0, 2.0]);
+
+    // when an incomparable value is inserted from the back
+    list.insert_sorted(f64::NAN, SearchFrom::Back);
+
+    // then it is positioned as though equal to its neighbour
+    assert!(list.iter().nth(2).unwrap().borrow().data.is_nan());
+}
+
+#[test]
+fn insert_sorted_by_orders_by_the_supplied_comparator() {
+    // given an empty list
+    let mut list = DoublyLinkedList::new();
+
+    // when data is inserted in descending order
+    for &datum in &[2, 5, 1, 4] {
+        list.insert_sorted_by(datum, SearchFrom::Front, |lhs: &i32, rhs: &i32| rhs.cmp(lhs));
+    }
+
+    // then the list is descending in both directions
+    assert_eq!(forward(&list), vec![5, 4, 2, 1]);
+    assert_eq!(backward(&list), vec![5, 4, 2, 1]);
+}
@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn iter_from_empty_list_returns_expected_iterator() {
     // given an iterator over an empty list
-    let expected_result = Iter(None);
+    let expected_result = Iter::new(None, &Rc::new(Cell::new(0)));
     let list = DoublyLinkedList::<String>::new();
 
     // when .iter() is called
@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn merge_interleaves_two_ascending_lists() {
+    // given two ascending lists
+    let mut list = list_of(&[1, 4, 6, 9]);
+    let other = list_of(&[0, 2, 4, 5, 10, 11]);
+
+    // when merge() is called
+    list.merge(other);
+
+    // then the list holds every element in ascending order, in both directions
+    assert_eq!(forward(&list), vec![0, 1, 2, 4, 4, 5, 6, 9, 10, 11]);
+    assert_eq!(backward(&list), vec![0, 1, 2, 4, 4, 5, 6, 9, 10, 11]);
+}
+
+#[test]
+fn merge_with_an_empty_list_on_either_side_yields_the_other_list() {
+    // given an empty list and a non-empty list
+    let mut empty = DoublyLinkedList::new();
+    let mut non_empty = list_of(&[1, 2]);
+
+    // when each is merged with an empty list
+    empty.merge(list_of(&[3, 4]));
+    non_empty.merge(DoublyLinkedList::new());
+
+    // then each holds the non-empty list's elements, in both directions
+    assert_eq!(forward(&empty), vec![3, 4]);
+    assert_eq!(backward(&empty), vec![3, 4]);
+    assert_eq!(forward(&non_empty), vec![1, 2]);
+    assert_eq!(backward(&non_empty), vec![1, 2]);
+}
+
+#[test]
+fn merge_by_is_stable_with_respect_to_equal_keys() {
+    // given two lists ascending by key, with keys in common
+    let mut list = list_of(&[(1, 'a'), (2, 'b'), (2, 'c')]);
+    let other = list_of(&[(1, 'x'), (2, 'y'), (3, 'z')]);
+
+    // when merge_by() is called comparing keys only
+    list.merge_by(other, |lhs, rhs| lhs.0.cmp(&rhs.0));
+
+    // then elements from the list precede equal elements from the other list
+    assert_eq!(forward(&list).iter().map(|(_, tag)| *tag).collect::<String>(), "axbcyz");
+    assert_eq!(backward(&list), forward(&list));
+}
+
+#[test]
+fn merge_adopts_the_other_lists_nodes() {
+    // given two ascending lists and a handle to a node in the second list
+    let mut list = list_of(&[1, 3]);
+    let other = list_of(&[2]);
+    let node = other.iter().next().unwrap();
+
+    // when merge() is called
+    list.merge(other);
+
+    // then the handle refers to a node of the merged list
+    assert_eq!(list.move_to_front(&node).map(|_| ()), Ok(()));
+    assert_eq!(forward(&list), vec![2, 1, 3]);
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn move_after_the_tail_relinks_the_node_as_the_tail() {
+    // given a list with three nodes and handles to its head and tail
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().next().unwrap();
+    let anchor = list.iter().nth(2).unwrap();
+
+    // when move_after() is called
+    list.move_after(&node, &anchor).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![2, 3, 1]);
+    assert_eq!(backward(&list), vec![2, 3, 1]);
+}
+
+#[test]
+fn move_after_the_following_node_swaps_adjacent_nodes() {
+    // given a list with four nodes and handles to two adjacent middle nodes
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let node = list.iter().nth(1).unwrap();
+    let anchor = list.iter().nth(2).unwrap();
+
+    // when move_after() is called
+    list.move_after(&node, &anchor).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![1, 3, 2, 4]);
+    assert_eq!(backward(&list), vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn move_after_of_the_tail_to_the_head_relinks_it_second() {
+    // given a list with three nodes and handles to its tail and head
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(2).unwrap();
+    let anchor = list.iter().next().unwrap();
+
+    // when move_after() is called
+    list.move_after(&node, &anchor).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![1, 3, 2]);
+    assert_eq!(backward(&list), vec![1, 3, 2]);
+}
+
+#[test]
+fn move_after_of_a_node_from_another_list_yields_an_error() {
+    // given a node in one list and an anchor in another
+    let list = list_of(&[1, 2]);
+    let mut other = list_of(&[3]);
+    let node = list.iter().next().unwrap();
+    let anchor = other.iter().next().unwrap();
+
+    // when move_after() is called on the anchor's list
+    let result = other.move_after(&node, &anchor).map(|_| ());
+
+    // then the move is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::MoveAfter }));
+}
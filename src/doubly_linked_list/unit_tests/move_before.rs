@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn move_before_the_head_relinks_the_node_as_the_head() {
+    // given a list with three nodes and handles to its tail and head
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(2).unwrap();
+    let anchor = list.iter().next().unwrap();
+
+    // when move_before() is called
+    list.move_before(&node, &anchor).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![3, 1, 2]);
+    assert_eq!(backward(&list), vec![3, 1, 2]);
+}
+
+#[test]
+fn move_before_the_preceding_node_swaps_adjacent_nodes() {
+    // given a list with four nodes and handles to two adjacent middle nodes
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let node = list.iter().nth(2).unwrap();
+    let anchor = list.iter().nth(1).unwrap();
+
+    // when move_before() is called
+    list.move_before(&node, &anchor).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![1, 3, 2, 4]);
+    assert_eq!(backward(&list), vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn move_before_the_following_node_leaves_the_list_unchanged() {
+    // given a list with three nodes and handles to its head and middle nodes
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().next().unwrap();
+    let anchor = list.iter().nth(1).unwrap();
+
+    // when move_before() is called
+    list.move_before(&node, &anchor).unwrap();
+
+    // then the nodes are in the original order in both directions
+    assert_eq!(forward(&list), vec![1, 2, 3]);
+    assert_eq!(backward(&list), vec![1, 2, 3]);
+}
+
+#[test]
+fn move_before_itself_leaves_the_list_unchanged() {
+    // given a list with two nodes and a handle to its tail
+    let mut list = list_of(&[1, 2]);
+    let node = list.iter().nth(1).unwrap();
+
+    // when move_before() is called with the node as its own anchor
+    list.move_before(&node, &node.clone()).unwrap();
+
+    // then the nodes are in the original order in both directions
+    assert_eq!(forward(&list), vec![1, 2]);
+    assert_eq!(backward(&list), vec![1, 2]);
+}
+
+#[test]
+fn move_before_an_anchor_from_another_list_yields_an_error() {
+    // given a node in one list and an anchor in another
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let node = list.iter().next().unwrap();
+    let anchor = other.iter().next().unwrap();
+
+    // when move_before() is called
+    let result = list.move_before(&node, &anchor).map(|_| ());
+
+    // then the move is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::MoveBefore }));
+
+    // and the list is not modified
+    assert_eq!(forward(&list), vec![1, 2]);
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn move_to_back_of_the_head_relinks_it_as_the_tail() {
+    // given a list with three nodes and a handle to its head
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().next().unwrap();
+
+    // when move_to_back() is called
+    let result = list.move_to_back(&node).map(|_| ());
+
+    // then the move succeeds
+    assert_eq!(result, Ok(()));
+
+    // and the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![2, 3, 1]);
+    assert_eq!(backward(&list), vec![2, 3, 1]);
+}
+
+#[test]
+fn move_to_back_of_the_tail_leaves_the_list_unchanged() {
+    // given a list with one node and a handle to it
+    let mut list = list_of(&[1]);
+    let node = list.iter().next().unwrap();
+
+    // when move_to_back() is called
+    list.move_to_back(&node).unwrap();
+
+    // then the list is unchanged in both directions
+    assert_eq!(forward(&list), vec![1]);
+    assert_eq!(backward(&list), vec![1]);
+}
+
+#[test]
+fn move_to_back_of_a_popped_node_yields_an_error() {
+    // given a list with two nodes and a handle to its head
+    let mut list = list_of(&[1, 2]);
+    let node = list.iter().next().unwrap();
+
+    // and a failed attempt to pop the head (which unlinks it from the list)
+    assert!(list.pop_front().is_err());
+
+    // when move_to_back() is called with the unlinked node
+    let result = list.move_to_back(&node).map(|_| ());
+
+    // then the move is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::MoveToBack }));
+
+    // and the list is left consistent
+    assert_eq!(forward(&list), vec![2]);
+    assert_eq!(backward(&list), vec![2]);
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+#[test]
+fn move_to_front_of_the_tail_relinks_it_as_the_head() {
+    // given a list with three nodes
+    let mut list = list_of(&[1, 2, 3]);
+
+    // and a handle to its tail
+    let node = list.iter().nth(2).unwrap();
+
+    // when move_to_front() is called
+    let result = list.move_to_front(&node).map(|_| ());
+
+    // then the move succeeds
+    assert_eq!(result, Ok(()));
+
+    // and the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![3, 1, 2]);
+    assert_eq!(backward(&list), vec![3, 1, 2]);
+}
+
+#[test]
+fn move_to_front_of_a_middle_node_relinks_it_as_the_head() {
+    // given a list with three nodes and a handle to its middle node
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(1).unwrap();
+
+    // when move_to_front() is called
+    list.move_to_front(&node).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![2, 1, 3]);
+    assert_eq!(backward(&list), vec![2, 1, 3]);
+}
+
+#[test]
+fn move_to_front_of_the_head_leaves_the_list_unchanged() {
+    // given a list with two nodes and a handle to its head
+    let mut list = list_of(&[1, 2]);
+    let node = list.iter().next().unwrap();
+
+    // when move_to_front() is called
+    list.move_to_front(&node).unwrap();
+
+    // then the nodes are in the original order in both directions
+    assert_eq!(forward(&list), vec![1, 2]);
+    assert_eq!(backward(&list), vec![1, 2]);
+}
+
+#[test]
+fn move_to_front_preserves_the_node_behind_outstanding_handles() {
+    // given a list with three nodes and a handle to its tail
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(2).unwrap();
+
+    // when move_to_front() is called
+    list.move_to_front(&node).unwrap();
+
+    // then the handle refers to the new head
+    assert!(Rc::ptr_eq(&node, &list.iter().next().unwrap()));
+
+    // and once the handle is released, the relinked node can be popped
+    drop(node);
+    assert_eq!(list.pop_front(), Ok(3));
+    assert_eq!(list.pop_back(), Ok(2));
+    assert_eq!(list.pop_back(), Ok(1));
+}
+
+#[test]
+fn move_to_front_of_a_node_from_another_list_yields_an_error() {
+    // given two lists and a handle to a node in the second list
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let node = other.iter().next().unwrap();
+
+    // when move_to_front() is called on the first list
+    let result = list.move_to_front(&node).map(|_| ());
+
+    // then the move is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::MoveToFront }));
+
+    // and neither list is modified
+    assert_eq!(forward(&list), vec![1, 2]);
+    assert_eq!(forward(&other), vec![3]);
+}
@@ -9,5 +9,5 @@ fn new_creates_an_empty_list() {
     let result = sut();
 
     // then the list should be empty
-    assert_eq!(result.is_empty(), true);
+    assert!(result.is_empty());
 }
\ No newline at end of file
@@ -0,0 +1,121 @@
+use super::*;
+
+#[test]
+fn list_without_a_pool_allocates_every_node() {
+    // given a list without a pool
+    let mut list = DoublyLinkedList::new();
+
+    // when nodes are repeatedly pushed and popped
+    for datum in 0..3 {
+        list.push_back(datum);
+        list.pop_front().unwrap();
+    }
+
+    // then every insertion allocates
+    assert_eq!(list.allocation_stats(), AllocationStats { allocated: 3, reused: 0, pooled: 0 });
+    assert_eq!(list.pool_capacity(), 0);
+}
+
+#[test]
+fn list_with_a_pool_reuses_released_nodes() {
+    // given a list with a pool
+    let mut list = DoublyLinkedList::<String>::with_pool(4);
+
+    // when nodes are used as a queue
+    for datum in 0..10 {
+        list.push_back(datum.to_string());
+        assert_eq!(list.pop_front(), Ok(datum.to_string()));
+    }
+
+    // then only the first insertion allocates, and every later insertion reuses the released node
+    assert_eq!(list.allocation_stats(), AllocationStats { allocated: 1, reused: 9, pooled: 1 });
+}
+
+#[test]
+fn pool_retains_no_more_than_its_capacity() {
+    // given a list with a pool of capacity 2, holding 4 nodes
+    let mut list = DoublyLinkedList::with_pool(2);
+    (0..4).for_each(|datum| { list.push_back(datum); });
+
+    // when every node is removed, by each removal method
+    list.pop_front().unwrap();
+    list.pop_back().unwrap();
+    let node = list.iter().next().unwrap();
+    list.remove(node).unwrap();
+    list.pop_back().unwrap();
+
+    // then the pool retains only its capacity
+    assert_eq!(list.allocation_stats().pooled, 2);
+
+    // and reused nodes are linked correctly
+    list.push_front(5).push_back(6).push_front(4);
+    assert_eq!(forward(&list), vec![4, 5, 6]);
+    assert_eq!(backward(&list), vec![4, 5, 6]);
+    assert_eq!(list.allocation_stats(), AllocationStats { allocated: 5, reused: 2, pooled: 0 });
+}
+
+#[test]
+fn reserve_pre_allocates_nodes_for_subsequent_insertions() {
+    // given a list without a pool
+    let mut list = DoublyLinkedList::new();
+
+    // when nodes are reserved
+    list.reserve(3);
+
+    // then the pool holds the reserved nodes
+    assert_eq!(list.allocation_stats(), AllocationStats { allocated: 3, reused: 0, pooled: 3 });
+    assert_eq!(list.pool_capacity(), 3);
+
+    // and subsequent insertions do not allocate
+    list.push_back(1).push_back(2).push_front(0);
+    assert_eq!(list.allocation_stats(), AllocationStats { allocated: 3, reused: 3, pooled: 0 });
+    assert_eq!(forward(&list), vec![0, 1, 2]);
+}
+
+#[test]
+fn shrink_to_fit_releases_pooled_nodes() {
+    // given a list with reserved nodes
+    let mut list = DoublyLinkedList::<u8>::new();
+    list.reserve(5);
+
+    // when shrink_to_fit() is called
+    list.shrink_to_fit();
+
+    // then the pool is empty but retains its capacity
+    assert_eq!(list.allocation_stats().pooled, 0);
+    assert_eq!(list.pool_capacity(), 5);
+}
+
+#[test]
+fn set_pool_capacity_releases_pooled_nodes_beyond_the_new_capacity() {
+    // given a list with reserved nodes
+    let mut list = DoublyLinkedList::<u8>::new();
+    list.reserve(5);
+
+    // when the pool's capacity is reduced
+    list.set_pool_capacity(2);
+
+    // then excess pooled nodes are released
+    assert_eq!(list.allocation_stats().pooled, 2);
+    assert_eq!(list.pool_capacity(), 2);
+}
+
+#[test]
+fn nodes_with_live_references_are_not_pooled() {
+    // given a list with a pool and a handle to its head
+    let mut list = DoublyLinkedList::with_pool(1);
+    list.push_back(1).push_back(2);
+    let node = list.iter().next().unwrap();
+
+    // when the head is popped while the handle is held
+    let result = list.pop_front();
+
+    // then extraction fails
+    assert!(result.is_err());
+
+    // and the node is not placed in the pool
+    assert_eq!(list.allocation_stats().pooled, 0);
+
+    // and the handle's node is no longer a member of the list
+    assert_eq!(list.move_to_back(&node).map(|_| ()), Err(Error::NodeNotInList { op: Operation::MoveToBack }));
+}
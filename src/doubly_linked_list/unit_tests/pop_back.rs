@@ -1,12 +1,13 @@
 use crate::{
     DoublyLinkedList,
     Error,
+    Operation,
 };
 
 #[test]
 fn pop_back_on_an_empty_list_yields_none() {
     // given an empty list
-    let expected_result = Err(Error::EmptyList);
+    let expected_result = Err(Error::EmptyList { op: Operation::PopBack });
     let mut list = DoublyLinkedList::<String>::new();
 
     // when pop_back() is called
@@ -39,3 +40,20 @@ fn pop_back_on_a_non_empty_list_yields_the_expected_data() {
     // and the list reports a length of 1
     assert_eq!(list.len(), 1);
 }
+
+#[test]
+fn pop_back_on_a_node_with_a_live_reference_yields_an_error_with_context() {
+    // given a list with two nodes
+    let mut list = DoublyLinkedList::<String>::new();
+    list.push_back(String::from("sample test data"));
+    list.push_back(String::from("other sample test data"));
+
+    // and a live reference to its tail
+    let _live_ref = list.iter().nth(1);
+
+    // when pop_back() is called
+    let result = list.pop_back();
+
+    // then the list reports the operation, position and reference counts which prevented extraction
+    assert_eq!(result, Err(Error::ExistingLiveReferences { op: Operation::PopBack, index: 1, strong: 2, weak: 0 }));
+}
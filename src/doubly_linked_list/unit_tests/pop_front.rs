@@ -1,12 +1,13 @@
 use crate::{
     DoublyLinkedList,
     Error,
+    Operation,
 };
 
 #[test]
 fn pop_front_on_an_empty_list_yields_none() {
     // given an empty list
-    let expected_result = Err(Error::EmptyList);
+    let expected_result = Err(Error::EmptyList { op: Operation::PopFront });
     let mut list = DoublyLinkedList::<String>::new();
 
     // when pop_front() is called
@@ -39,3 +40,51 @@ fn pop_front_on_a_non_empty_list_yields_the_expected_data() {
     // and the list reports a length of 1
     assert_eq!(list.len(), 1);
 }
+
+#[test]
+fn pop_front_on_a_node_with_a_live_reference_yields_an_error_with_context() {
+    // given a non-empty list
+    let mut list = DoublyLinkedList::<String>::new();
+    list.push_back(String::from("sample test data"));
+
+    // and a live reference to its head
+    let _live_ref = list.iter().next();
+
+    // when pop_front() is called
+    let result = list.pop_front();
+
+    // then the list reports the operation, position and reference counts which prevented extraction
+    assert_eq!(result, Err(Error::ExistingLiveReferences { op: Operation::PopFront, index: 0, strong: 2, weak: 0 }));
+}
+
+#[test]
+fn pop_front_leaves_the_new_head_without_a_predecessor() {
+    // given a list with two nodes
+    let mut list = DoublyLinkedList::<String>::new();
+    list.push_back(String::from("sample test data"));
+    list.push_back(String::from("other sample test data"));
+
+    // when pop_front() is called
+    let _ = list.pop_front();
+
+    // then the new head does not refer back to the extracted node
+    assert!(list.iter().next().is_some_and(|head| head.borrow().prev.is_none()));
+}
+
+#[test]
+fn insert_before_the_head_after_pop_front_succeeds() {
+    // given a list with two nodes, whose head has been popped
+    let mut list = DoublyLinkedList::<String>::new();
+    list.push_back(String::from("sample test data"));
+    list.push_back(String::from("other sample test data"));
+    let _ = list.pop_front();
+
+    // when data is inserted before the new head
+    if let Some(head) = list.iter().next() {
+        list.insert_before(head, String::from("new sample test data")).unwrap();
+    }
+
+    // then the data becomes the head, rather than following the dangling link to the popped node
+    assert_eq!(list.iter().map(|link| link.borrow().data.clone()).collect::<Vec<_>>(),
+               vec![String::from("new sample test data"), String::from("other sample test data")]);
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::option_unwrap_used)]
+#![allow(clippy::unwrap_used)]
 use crate::DoublyLinkedList;
 
 #[test]
@@ -11,7 +11,7 @@ fn push_back_on_an_empty_list_yields_non_empty_list_of_expected_length() {
     let result = list.push_back(sample_data.clone());
 
     // then the list does not report empty
-    assert_eq!(result.is_empty(), false);
+    assert!(!result.is_empty());
 
     // and the list reports a length of 1
     assert_eq!(list.len(), 1);
@@ -29,7 +29,7 @@ fn push_back_on_a_list_with_one_node_yields_a_list_of_expected_length_and_order(
     list.push_back(sample_data_2.clone());
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 2);
@@ -55,7 +55,7 @@ fn push_back_on_a_list_with_one_node_in_a_different_order_yields_a_list_of_expec
     list.push_back(sample_data_1.clone());
 
     // then the list does not report empty
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 
     // and the list reports a length of 2
     assert_eq!(list.len(), 2);
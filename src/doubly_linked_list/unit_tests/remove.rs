@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn remove_of_a_middle_node_yields_its_data_and_joins_its_neighbours() {
+    // given a list with three nodes and a handle to its middle node
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(1).unwrap();
+
+    // when remove() is called
+    let result = list.remove(node);
+
+    // then the node's data is returned
+    assert_eq!(result, Ok(2));
+
+    // and the neighbours are joined in both directions
+    assert_eq!(forward(&list), vec![1, 3]);
+    assert_eq!(backward(&list), vec![1, 3]);
+}
+
+#[test]
+fn remove_of_the_only_node_yields_an_empty_list() {
+    // given a list with one node and a handle to it
+    let mut list = list_of(&[1]);
+    let node = list.iter().next().unwrap();
+
+    // when remove() is called
+    let result = list.remove(node);
+
+    // then the node's data is returned
+    assert_eq!(result, Ok(1));
+
+    // and the list is empty
+    assert!(list.is_empty());
+    assert!(list.tail.is_none());
+}
+
+#[test]
+fn remove_of_a_node_with_another_live_reference_leaves_the_list_unmodified() {
+    // given a list with three nodes and two handles to its tail
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.iter().nth(2).unwrap();
+    let _other_handle = node.clone();
+
+    // when remove() is called
+    let result = list.remove(node);
+
+    // then the removal is rejected with the node's position
+    assert_eq!(result, Err(Error::ExistingLiveReferences { op: Operation::Remove, index: 2, strong: 3, weak: 1 }));
+
+    // and the list is unmodified
+    assert_eq!(forward(&list), vec![1, 2, 3]);
+    assert_eq!(backward(&list), vec![1, 2, 3]);
+}
+
+#[test]
+fn remove_of_a_node_from_another_list_yields_an_error() {
+    // given a list and a handle to a node in another list
+    let mut list = list_of(&[1]);
+    let other = list_of(&[2]);
+    let node = other.iter().next().unwrap();
+
+    // when remove() is called
+    let result = list.remove(node);
+
+    // then the removal is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::Remove }));
+}
@@ -0,0 +1,72 @@
+use super::*;
+use core::{
+    cell::Cell,
+    mem::size_of,
+};
+
+#[test]
+fn stats_of_an_unshared_list_reports_one_strong_and_one_weak_reference_per_node() {
+    // given a list with no outstanding references to its nodes
+    let list = list_of(&[1, 2, 3]);
+
+    // when its stats are gathered
+    let result = list.stats();
+
+    // then every node is referenced only by the list
+    assert_eq!(result.len, 3);
+    assert_eq!(result.ref_counts, vec![RefCounts { strong: 1, weak: 1 }; 3]);
+    assert_eq!(result.externally_held_count(), 0);
+}
+
+#[test]
+fn stats_locates_externally_held_nodes() {
+    // given a list with outstanding references to its head and tail
+    let list = list_of(&[1, 2, 3]);
+    let head = list.iter().next().unwrap();
+    let tail = list.iter().nth(2).unwrap();
+    let _tail_again = tail.clone();
+
+    // when its stats are gathered
+    let result = list.stats();
+
+    // then the outstanding references are counted and their positions reported
+    assert_eq!(result.ref_counts.first(), Some(&RefCounts { strong: 2, weak: 1 }));
+    assert_eq!(result.ref_counts.get(2), Some(&RefCounts { strong: 3, weak: 1 }));
+    assert_eq!(result.externally_held, vec![0, 2]);
+    drop(head);
+}
+
+#[test]
+fn stats_explains_a_failed_pop() {
+    // given a list whose head cannot be popped because of an outstanding reference
+    let mut list = list_of(&[1, 2]);
+    let head = list.iter().next().unwrap();
+    let stats_before_pop = list.stats();
+
+    // when the head is popped
+    let result = list.pop_front();
+
+    // then the pop fails, and the stats identified the culprit beforehand
+    assert!(result.is_err());
+    assert_eq!(stats_before_pop.externally_held, vec![0]);
+    drop(head);
+}
+
+#[test]
+fn stats_estimates_heap_bytes_including_pooled_nodes() {
+    // given an empty list, and a list with a pooled allocation
+    let empty = DoublyLinkedList::<u64>::new();
+    let mut pooled = DoublyLinkedList::<u64>::with_pool(1);
+    pooled.push_back(1);
+    pooled.pop_back().unwrap();
+
+    // when their stats are gathered
+    let (empty_bytes, pooled_bytes) = (empty.stats().heap_bytes, pooled.stats().heap_bytes);
+
+    // then the empty list holds only bookkeeping
+    let counts = 2 * size_of::<usize>();
+    assert_eq!(empty_bytes, counts + counts + size_of::<Cell<usize>>());
+
+    // and the pooled allocation is accounted for
+    assert_eq!(pooled_bytes, empty_bytes + counts + size_of::<RefCell<Node<u64>>>() + size_of::<Rc<()>>());
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn swap_data_exchanges_payloads_and_leaves_nodes_in_place() {
+    // given a list with three nodes and handles to its head and tail
+    let mut list = list_of(&[1, 2, 3]);
+    let a = list.iter().next().unwrap();
+    let b = list.iter().nth(2).unwrap();
+
+    // when swap_data() is called
+    let result = list.swap_data(&a, &b).map(|_| ());
+
+    // then the swap succeeds
+    assert_eq!(result, Ok(()));
+
+    // and the data is in the expected order
+    assert_eq!(forward(&list), vec![3, 2, 1]);
+
+    // and each handle still refers to the node at its original position
+    assert!(Rc::ptr_eq(&a, &list.iter().next().unwrap()));
+    assert_eq!(a.borrow().data, 3);
+}
+
+#[test]
+fn swap_data_of_a_node_with_itself_leaves_the_list_unchanged() {
+    // given a list with two nodes and a handle to its head
+    let mut list = list_of(&[1, 2]);
+    let a = list.iter().next().unwrap();
+
+    // when swap_data() is called with the node as both arguments
+    list.swap_data(&a, &a.clone()).unwrap();
+
+    // then the data is in the original order
+    assert_eq!(forward(&list), vec![1, 2]);
+}
+
+#[test]
+fn swap_data_of_a_node_from_another_list_yields_an_error() {
+    // given a node in one list and a node in another
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let a = list.iter().next().unwrap();
+    let b = other.iter().next().unwrap();
+
+    // when swap_data() is called
+    let result = list.swap_data(&a, &b).map(|_| ());
+
+    // then the swap is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::SwapData }));
+
+    // and neither list is modified
+    assert_eq!(forward(&list), vec![1, 2]);
+    assert_eq!(forward(&other), vec![3]);
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+#[test]
+fn swap_nodes_of_the_head_and_tail_exchanges_their_positions() {
+    // given a list with four nodes and handles to its head and tail
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let a = list.iter().next().unwrap();
+    let b = list.iter().nth(3).unwrap();
+
+    // when swap_nodes() is called
+    let result = list.swap_nodes(&a, &b).map(|_| ());
+
+    // then the swap succeeds
+    assert_eq!(result, Ok(()));
+
+    // and the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![4, 2, 3, 1]);
+    assert_eq!(backward(&list), vec![4, 2, 3, 1]);
+}
+
+#[test]
+fn swap_nodes_of_adjacent_nodes_exchanges_their_positions() {
+    // given a list with four nodes and handles to two adjacent middle nodes
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let a = list.iter().nth(1).unwrap();
+    let b = list.iter().nth(2).unwrap();
+
+    // when swap_nodes() is called
+    list.swap_nodes(&a, &b).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![1, 3, 2, 4]);
+    assert_eq!(backward(&list), vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn swap_nodes_of_adjacent_nodes_in_reverse_order_exchanges_their_positions() {
+    // given a list with two nodes and handles to its tail and head, in that order
+    let mut list = list_of(&[1, 2]);
+    let a = list.iter().nth(1).unwrap();
+    let b = list.iter().next().unwrap();
+
+    // when swap_nodes() is called
+    list.swap_nodes(&a, &b).unwrap();
+
+    // then the nodes are in the expected order in both directions
+    assert_eq!(forward(&list), vec![2, 1]);
+    assert_eq!(backward(&list), vec![2, 1]);
+}
+
+#[test]
+fn swap_nodes_of_a_node_with_itself_leaves_the_list_unchanged() {
+    // given a list with three nodes and a handle to its middle node
+    let mut list = list_of(&[1, 2, 3]);
+    let a = list.iter().nth(1).unwrap();
+
+    // when swap_nodes() is called with the node as both arguments
+    list.swap_nodes(&a, &a.clone()).unwrap();
+
+    // then the nodes are in the original order in both directions
+    assert_eq!(forward(&list), vec![1, 2, 3]);
+    assert_eq!(backward(&list), vec![1, 2, 3]);
+}
+
+#[test]
+fn swap_nodes_of_a_node_from_another_list_yields_an_error() {
+    // given a node in one list and a node in another
+    let mut list = list_of(&[1, 2]);
+    let other = list_of(&[3]);
+    let a = list.iter().next().unwrap();
+    let b = other.iter().next().unwrap();
+
+    // when swap_nodes() is called
+    let result = list.swap_nodes(&a, &b).map(|_| ());
+
+    // then the swap is rejected
+    assert_eq!(result, Err(Error::NodeNotInList { op: Operation::SwapNodes }));
+
+    // and neither list is modified
+    assert_eq!(forward(&list), vec![1, 2]);
+    assert_eq!(forward(&other), vec![3]);
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+#[test]
+fn to_dot_of_an_empty_list_emits_only_the_markers() {
+    // given an empty list
+    let list = DoublyLinkedList::<i32>::new();
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then only the head and tail markers are emitted
+    assert_eq!(result, "digraph DoublyLinkedList {\n    rankdir=LR;\n    node [shape=box];\n    \
+                        head [shape=plaintext];\n    tail [shape=plaintext];\n}\n");
+}
+
+#[test]
+fn to_dot_emits_vertices_and_edges_for_a_well_formed_list() {
+    // given a well-formed list
+    let list = list_of(&["a", "b"]);
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then each node is labelled with its data and reference counts
+    assert!(result.contains("    n0 [label=\"\\\"a\\\"\\nstrong: 1, weak: 1\"];\n"));
+    assert!(result.contains("    n1 [label=\"\\\"b\\\"\\nstrong: 1, weak: 1\"];\n"));
+
+    // and `next` links are solid, `prev` links dashed, and the markers point at the ends
+    assert!(result.contains("    head -> n0;\n"));
+    assert!(result.contains("    n0 -> n1;\n"));
+    assert!(result.contains("    n1 -> n0 [style=dashed];\n"));
+    assert!(result.contains("    tail -> n1 [style=dashed];\n"));
+
+    // and nothing is highlighted
+    assert!(!result.contains("red"));
+}
+
+#[test]
+fn to_dot_counts_outstanding_node_links() {
+    // given a list with an outstanding `NodeLink` to its head
+    let list = list_of(&[1, 2]);
+    let _head = list.iter().next().unwrap();
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then the outstanding reference is counted
+    assert!(result.contains("    n0 [label=\"1\\nstrong: 2, weak: 1\"];\n"));
+}
+
+#[test]
+fn to_dot_highlights_a_prev_link_to_the_wrong_node() {
+    // given a list whose tail's `prev` link skips its predecessor
+    let list = list_of(&[1, 2, 3]);
+    let head = list.iter().next().unwrap();
+    list.iter().nth(2).unwrap().borrow_mut().prev = Some(head.to_weak());
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then the corrupt link is drawn in red
+    assert!(result.contains("    n2 -> n0 [style=dashed, color=red];\n"));
+}
+
+#[test]
+fn to_dot_highlights_dangling_and_missing_prev_links() {
+    // given a list whose second node's `prev` link dangles and whose third node's `prev` link is absent
+    let list = list_of(&[1, 2, 3]);
+    let gone = NodeLink::new(Node::new(0));
+    list.iter().nth(1).unwrap().borrow_mut().prev = Some(gone.to_weak());
+    drop(gone);
+    list.iter().nth(2).unwrap().borrow_mut().prev = None;
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then both broken links are drawn in red
+    assert!(result.contains("    n1 -> n1_broken [style=dashed, color=red];\n"));
+    assert!(result.contains("    n1_broken [label=\"dangling\", color=red, fontcolor=red];\n"));
+    assert!(result.contains("    n2_broken [label=\"missing\", color=red, fontcolor=red];\n"));
+}
+
+#[test]
+fn to_dot_highlights_a_prev_link_to_a_node_outside_the_list() {
+    // given a list whose head has a `prev` link to a node outside the list
+    let list = list_of(&[1]);
+    let stray = NodeLink::new(Node::new(0));
+    list.iter().next().unwrap().borrow_mut().prev = Some(stray.to_weak());
+
+    // when it is rendered
+    let result = list.to_dot();
+
+    // then the stray link is drawn in red
+    assert!(result.contains("    n0_broken [label=\"not in list\", color=red, fontcolor=red];\n"));
+}
@@ -1,24 +1,212 @@
-use crate::consts::*;
-use std::fmt::{
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg::{
+        self,
+        MsgKey,
+    },
+    Locale,
+};
+use alloc::{
+    format,
+    string::String,
+};
+use core::fmt::{
     Display,
     Formatter,
     Result as FmtResult,
 };
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+
+/// Identifies the operation during which an `Error` arose: an operation of `DoublyLinkedList`, or of a collection built
+/// on it (e.g. `BoundedList::push()`, or `Dlx`'s `add_row()`, `cover()` and `uncover()`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    AddRow,
+    Cover,
+    InsertAfter,
+    InsertAt,
+    InsertBefore,
+    IterBackFrom,
+    IterFrom,
+    MoveAfter,
+    MoveBefore,
+    MoveToBack,
+    MoveToFront,
+    PopBack,
+    PopFront,
+    Push,
+    Range,
+    RangeInclusive,
+    Redo,
+    Remove,
+    RemoveAt,
+    RemoveCurrent,
+    SwapData,
+    SwapNodes,
+    TryNext,
+    Uncover,
+    Undo,
+}
+
+impl Operation {
+    /// The name of the method which implements this operation.
+    pub fn name(self) -> &'static str {
+        match self {
+            Operation::AddRow => "add_row",
+            Operation::Cover => "cover",
+            Operation::InsertAfter => "insert_after",
+            Operation::InsertAt => "insert_at",
+            Operation::InsertBefore => "insert_before",
+            Operation::IterBackFrom => "iter_back_from",
+            Operation::IterFrom => "iter_from",
+            Operation::MoveAfter => "move_after",
+            Operation::MoveBefore => "move_before",
+            Operation::MoveToBack => "move_to_back",
+            Operation::MoveToFront => "move_to_front",
+            Operation::PopBack => "pop_back",
+            Operation::PopFront => "pop_front",
+            Operation::Push => "push",
+            Operation::Range => "range",
+            Operation::RangeInclusive => "range_inclusive",
+            Operation::Redo => "redo",
+            Operation::Remove => "remove",
+            Operation::RemoveAt => "remove_at",
+            Operation::RemoveCurrent => "remove_current",
+            Operation::SwapData => "swap_data",
+            Operation::SwapNodes => "swap_nodes",
+            Operation::TryNext => "try_next",
+            Operation::Uncover => "uncover",
+            Operation::Undo => "undo",
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "`{}`", self.name())
+    }
+}
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
-    EmptyList,
-    ExistingLiveReferences(usize)
+    /// `op` would have raised the total weight of a bounded list's elements to `required`, exceeding its `budget`.
+    CapacityExceeded { op: Operation, budget: usize, required: usize },
+    /// `op` observed that the list was structurally modified after the iterator performing it was created.
+    ConcurrentModification { op: Operation },
+    /// `op` requires at least one `Node`, but the list was empty.
+    EmptyList { op: Operation },
+    /// `op` requires sole ownership of the `Node` at position `index`, but `strong` strong and `weak` weak references
+    /// to it remained live.
+    ExistingLiveReferences { op: Operation, index: usize, strong: usize, weak: usize },
+    /// `op` was passed `index`, which is out of bounds for a list of length `len`.
+    IndexOutOfBounds { op: Operation, index: usize, len: usize },
+    /// `op` was passed a `NodeLink` to a `Node` which does not belong to the list.
+    NodeNotInList { op: Operation },
+    /// `op` was passed a start and an end `NodeLink`, but the end `Node` does not follow the start `Node`.
+    NodeNotReachable { op: Operation },
+}
+
+impl Error {
+    /// Stable numeric code identifying this `Error`'s variant, suitable for logging and FFI.  Codes are never reused
+    /// or reassigned.
+    pub fn code(&self) -> u32 {
+        match self {
+            Error::EmptyList { .. } => 1,
+            Error::ExistingLiveReferences { .. } => 2,
+            Error::NodeNotInList { .. } => 3,
+            Error::CapacityExceeded { .. } => 4,
+            Error::IndexOutOfBounds { .. } => 5,
+            Error::ConcurrentModification { .. } => 6,
+            Error::NodeNotReachable { .. } => 7,
+        }
+    }
+
+    /// The operation which failed.
+    pub fn operation(&self) -> Operation {
+        match self {
+            Error::CapacityExceeded { op, .. } |
+            Error::ConcurrentModification { op } |
+            Error::EmptyList { op } |
+            Error::ExistingLiveReferences { op, .. } |
+            Error::IndexOutOfBounds { op, .. } |
+            Error::NodeNotInList { op } |
+            Error::NodeNotReachable { op } => *op,
+        }
+    }
+
+    /// Re-attributes this `Error` to `op`, for operations implemented in terms of other operations.
+    pub(crate) fn in_op(mut self, new_op: Operation) -> Self {
+        match &mut self {
+            Error::CapacityExceeded { op, .. } |
+            Error::ConcurrentModification { op } |
+            Error::EmptyList { op } |
+            Error::ExistingLiveReferences { op, .. } |
+            Error::IndexOutOfBounds { op, .. } |
+            Error::NodeNotInList { op } |
+            Error::NodeNotReachable { op } => *op = new_op,
+        }
+        self
+    }
+
+    /// Renders this `Error`'s description in the specified `locale`.  Messages missing from `locale`'s catalog fall
+    /// back to `Locale::EnUs`.
+    pub fn localized(&self, locale: Locale) -> String {
+        let text = |key| msg::lookup(locale, key);
+        match self {
+            Error::CapacityExceeded { op, budget, required } => format!("{} ({}: {}, {}: {}, {}: {})",
+                                                                        text(MsgKey::ErrCapacityExceeded),
+                                                                        text(MsgKey::CtxOperation),
+                                                                        op,
+                                                                        text(MsgKey::CtxBudget),
+                                                                        budget,
+                                                                        text(MsgKey::CtxRequired),
+                                                                        required),
+            Error::ConcurrentModification { op } => format!("{} ({}: {})",
+                                                            text(MsgKey::ErrConcurrentModification),
+                                                            text(MsgKey::CtxOperation),
+                                                            op),
+            Error::EmptyList { op } => format!("{} ({}: {})",
+                                               text(MsgKey::ErrEmptyList),
+                                               text(MsgKey::CtxOperation),
+                                               op),
+            Error::ExistingLiveReferences { op, index, strong, weak } =>
+                format!("{} {} {} ({}: {}, {}: {}, {}: {})",
+                        text(MsgKey::ErrExistingLiveReferences1),
+                        strong,
+                        text(MsgKey::ErrExistingLiveReferences2),
+                        text(MsgKey::CtxOperation),
+                        op,
+                        text(MsgKey::CtxIndex),
+                        index,
+                        text(MsgKey::CtxWeakReferences),
+                        weak),
+            Error::IndexOutOfBounds { op, index, len } => format!("{} ({}: {}, {}: {}, {}: {})",
+                                                                  text(MsgKey::ErrIndexOutOfBounds),
+                                                                  text(MsgKey::CtxOperation),
+                                                                  op,
+                                                                  text(MsgKey::CtxIndex),
+                                                                  index,
+                                                                  text(MsgKey::CtxLen),
+                                                                  len),
+            Error::NodeNotInList { op } => format!("{} ({}: {})",
+                                                   text(MsgKey::ErrNodeNotInList),
+                                                   text(MsgKey::CtxOperation),
+                                                   op),
+            Error::NodeNotReachable { op } => format!("{} ({}: {})",
+                                                      text(MsgKey::ErrNodeNotReachable),
+                                                      text(MsgKey::CtxOperation),
+                                                      op),
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "{}", match self {
-            Error::EmptyList => String::from(msg::ERR_EMPTY_LIST),
-            Error::ExistingLiveReferences(count) => format!("{} {} {}",
-                                                            msg::ERR_EXISTING_LIVE_REFERENCES_1,
-                                                            count,
-                                                            msg::ERR_EXISTING_LIVE_REFERENCES_2),
-        })
+        write!(f, "{}", self.localized(Locale::default()))
     }
 }
+
+#[cfg(feature = "std")]
+impl StdError for Error {}
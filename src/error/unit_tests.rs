@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+fn localized_renders_the_message_in_the_requested_locale() {
+    // given an error
+    let sut = Error::EmptyList { op: Operation::PopFront };
+
+    // when it is localized to `fr_FR`
+    let result = sut.localized(Locale::FrFr);
+
+    // then the French message is returned, along with the failed operation
+    assert_eq!(result, format!("{} ({}: `pop_front`)",
+                               msg::lookup(Locale::FrFr, MsgKey::ErrEmptyList),
+                               msg::lookup(Locale::FrFr, MsgKey::CtxOperation)));
+    assert!(!result.contains(msg::ERR_EMPTY_LIST));
+}
+
+#[test]
+fn localized_embeds_the_live_reference_context() {
+    // given an error carrying live reference context
+    let sut = Error::ExistingLiveReferences { op: Operation::PopBack, index: 4, strong: 3, weak: 1 };
+
+    // when it is localized to `ja_JP`
+    let result = sut.localized(Locale::JaJp);
+
+    // then the message surrounds the strong count with the localized fragments and appends the context
+    assert_eq!(result, format!("{} 3 {} ({}: `pop_back`, {}: 4, {}: 1)",
+                               msg::lookup(Locale::JaJp, MsgKey::ErrExistingLiveReferences1),
+                               msg::lookup(Locale::JaJp, MsgKey::ErrExistingLiveReferences2),
+                               msg::lookup(Locale::JaJp, MsgKey::CtxOperation),
+                               msg::lookup(Locale::JaJp, MsgKey::CtxIndex),
+                               msg::lookup(Locale::JaJp, MsgKey::CtxWeakReferences)));
+}
+
+#[test]
+fn display_renders_the_message_in_the_default_locale() {
+    // given an error
+    let sut = Error::ExistingLiveReferences { op: Operation::PopFront, index: 0, strong: 2, weak: 0 };
+
+    // when it is displayed
+    let result = sut.to_string();
+
+    // then the message is rendered in the default locale
+    assert_eq!(result, sut.localized(Locale::default()));
+}
+
+#[test]
+fn codes_are_stable_and_distinct_per_variant() {
+    // given one error of each variant
+    let empty_list = Error::EmptyList { op: Operation::PopBack };
+    let live_refs = Error::ExistingLiveReferences { op: Operation::PopBack, index: 0, strong: 2, weak: 0 };
+    let not_in_list = Error::NodeNotInList { op: Operation::MoveToFront };
+    let capacity = Error::CapacityExceeded { op: Operation::Push, budget: 1, required: 2 };
+    let out_of_bounds = Error::IndexOutOfBounds { op: Operation::InsertAt, index: 3, len: 2 };
+    let modified = Error::ConcurrentModification { op: Operation::TryNext };
+    let not_reachable = Error::NodeNotReachable { op: Operation::Range };
+
+    // when their codes are requested
+    let result = (empty_list.code(), live_refs.code(), not_in_list.code(), capacity.code(), out_of_bounds.code(),
+                  modified.code(), not_reachable.code());
+
+    // then each variant reports its published code
+    assert_eq!(result, (1, 2, 3, 4, 5, 6, 7));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_can_be_boxed_as_a_std_error() {
+    // given a fallible function returning a boxed `std::error::Error`
+    fn sut() -> std::result::Result<(), Box<dyn StdError>> {
+        Err(Error::EmptyList { op: Operation::PopFront })?;
+        Ok(())
+    }
+
+    // when it is invoked
+    let result = sut();
+
+    // then the crate's `Error` is propagated through `?`
+    assert_eq!(result.map_err(|err| err.to_string()), Err(Error::EmptyList { op: Operation::PopFront }.to_string()));
+}
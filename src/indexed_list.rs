@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    DoublyLinkedList,
+    Error,
+    NodeLink,
+    Operation,
+    Result,
+    WeakLink,
+};
+use alloc::{
+    rc::Rc,
+    vec::Vec,
+};
+use core::{
+    cell::RefCell,
+    convert::TryFrom,
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    ops::Deref,
+};
+
+/// Upper bound on the number of express lanes; with a promotion probability of 1/2, ample for any addressable length.
+const MAX_LEVELS: usize = 32;
+/// Arbitrary non-zero seed for the tower-height generator.  A fixed seed keeps the layout reproducible.
+const RNG_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+type TowerLink<T> = Rc<RefCell<Tower<T>>>;
+
+/// One level of a `Tower`: a link to the next `Tower` having at least as many levels, and the number of `Node`s
+/// (positions) spanned by that link.  `span` is meaningful only when `next` is `Some`.
+struct Lane<T> {
+    next: Option<TowerLink<T>>,
+    span: usize,
+}
+
+/// A stack of express lanes rising above a base `Node`.  The sentinel `Tower` at the head of every lane has no `Node`
+/// and occupies position 0; the `Node` at list index `i` occupies position `i + 1`.  `Tower`s refer to their `Node`s
+/// weakly, so they do not count as live references to them.
+struct Tower<T> {
+    node: Option<WeakLink<T>>,
+    lanes: Vec<Lane<T>>,
+}
+
+/// `IndexedList` wraps a `DoublyLinkedList`, maintaining skip-list style express lanes (with span counts) over its
+/// chain of `Node`s so that positional access, insertion and removal take expected O(log n) time rather than O(n).
+/// The lanes are kept consistent by every mutating method; read-only access to the underlying list is via `Deref`.
+pub struct IndexedList<T> {
+    list: DoublyLinkedList<T>,
+    sentinel: TowerLink<T>,
+    len: usize,
+    rng: u64,
+}
+
+impl<T> IndexedList<T> {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            list: DoublyLinkedList::new(),
+            sentinel: Rc::new(RefCell::new(Tower { node: None, lanes: Vec::new() })),
+            len: 0,
+            rng: RNG_SEED,
+        }
+    }
+
+    /// Returns a `NodeLink` to the `Node` at `index`, or `None` if `index` is out of bounds.  Expected O(log n).
+    pub fn get(&self, index: usize) -> Option<NodeLink<T>> {
+        match index < self.len {
+            true => index.checked_add(1).and_then(|pos| self.node_at(pos)),
+            false => None,
+        }
+    }
+
+    /// Inserts `data` as a `Node` at `index`, shifting the `Node` at `index` (if any) and its successors back by one.
+    /// Expected O(log n).  Returns an error if `index` is greater than the list's length.
+    pub fn insert_at(&mut self, index: usize, data: T) -> Result<&mut Self> {
+        let pos = match index <= self.len {
+            true => index.checked_add(1),
+            false => None,
+        }.ok_or(Error::IndexOutOfBounds { op: Operation::InsertAt, index, len: self.len })?;
+
+        let height = self.random_height();
+        self.grow_to(height);
+        let update = self.predecessors(pos);
+
+        // Link the new `Node` into the base chain
+        let node = match self.walk_from(&update, pos.saturating_sub(1)) {
+            Some(prev) => Some(self.list.insert_after_link(prev.clone(), data)),
+            None => {
+                self.list.push_front(data);
+                self.list.head_link()
+            },
+        };
+
+        // Raise a `Tower` over the new `Node` through its first `height` lanes, and widen the lanes passing over it
+        let tower = Rc::new(RefCell::new(Tower { node: node.as_ref().map(NodeLink::to_weak), lanes: Vec::new() }));
+        for (level, (pred, pred_pos)) in update.iter().enumerate() {
+            let mut pred = pred.borrow_mut();
+            if let Some(lane) = pred.lanes.get_mut(level) {
+                match level < height {
+                    true => {
+                        // `pred`'s old successor in this lane moves from `pred_pos + span` to `pred_pos + span + 1`
+                        let span = lane.span.saturating_add(*pred_pos).saturating_add(1).saturating_sub(pos);
+                        let next = lane.next.replace(Rc::clone(&tower));
+                        tower.borrow_mut().lanes.push(Lane { span: next.as_ref().map_or(0, |_| span), next });
+                        lane.span = pos.saturating_sub(*pred_pos);
+                    },
+                    false => if lane.next.is_some() {
+                        lane.span = lane.span.saturating_add(1);
+                    },
+                }
+            }
+        }
+        self.len = self.len.saturating_add(1);
+        Ok(self)
+    }
+
+    /// Predicate revealing whether the list is empty (contains no `Node`s) or not.  O(1).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of `Node`s currently in the list.  O(1).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Removes the `Node` at the tail of the list and returns the `data` contained within.
+    pub fn pop_back(&mut self) -> Result<T> {
+        match self.len.checked_sub(1) {
+            Some(index) => self.remove_at(index),
+            None => Err(Error::EmptyList { op: Operation::PopBack }),
+        }
+    }
+
+    /// Removes the `Node` at the head of the list and returns the `data` contained within.
+    pub fn pop_front(&mut self) -> Result<T> {
+        match self.len {
+            0 => Err(Error::EmptyList { op: Operation::PopFront }),
+            _ => self.remove_at(0),
+        }
+    }
+
+    /// Appends a `Node` to the end of the list.
+    pub fn push_back(&mut self, data: T) -> &mut Self {
+        let index = self.len;
+        self.insert_at(index, data)
+            .expect(msg::ERR_INTERNAL_BOUNDARY_INSERTION)
+    }
+
+    /// Prepends a `Node` to the front of the list.
+    pub fn push_front(&mut self, data: T) -> &mut Self {
+        self.insert_at(0, data)
+            .expect(msg::ERR_INTERNAL_BOUNDARY_INSERTION)
+    }
+
+    /// Removes the `Node` at `index` and returns the `data` contained within, shifting its successors forward by one.
+    /// Expected O(log n).  Returns an error (leaving the list unmodified) if `index` is out of bounds or if the `Node`
+    /// has outstanding live references.
+    pub fn remove_at(&mut self, index: usize) -> Result<T> {
+        let pos = match index < self.len {
+            true => index.checked_add(1),
+            false => None,
+        }.ok_or(Error::IndexOutOfBounds { op: Operation::RemoveAt, index, len: self.len })?;
+
+        let update = self.predecessors(pos);
+        let node = self.walk_from(&update, pos)
+                       .ok_or(Error::IndexOutOfBounds { op: Operation::RemoveAt, index, len: self.len })?;
+        let data = self.list
+                       .remove(node)
+                       .map_err(|err| err.in_op(Operation::RemoveAt))?;
+
+        // Unlink the removed `Node`'s `Tower` from each lane it occupied, and narrow the lanes passing over it
+        for (level, (pred, pred_pos)) in update.iter().enumerate() {
+            let mut pred = pred.borrow_mut();
+            if let Some(lane) = pred.lanes.get_mut(level) {
+                match lane.next.clone() {
+                    Some(next) if pred_pos.saturating_add(lane.span) == pos => {
+                        let mut next = next.borrow_mut();
+                        let removed_lane = next.lanes.get_mut(level);
+                        lane.next = removed_lane.as_ref().and_then(|removed| removed.next.clone());
+                        lane.span = match &lane.next {
+                            Some(_) => lane.span
+                                           .saturating_add(removed_lane.map_or(0, |removed| removed.span))
+                                           .saturating_sub(1),
+                            None => 0,
+                        };
+                    },
+                    Some(_) => lane.span = lane.span.saturating_sub(1),
+                    None => (),
+                }
+            }
+        }
+        self.len = self.len.saturating_sub(1);
+        self.shrink();
+        Ok(data)
+    }
+}
+
+// Private express-lane helpers
+impl<T> IndexedList<T> {
+    /// Adds empty lanes to the sentinel so that it has at least `height` lanes.
+    fn grow_to(&mut self, height: usize) {
+        let mut sentinel = self.sentinel.borrow_mut();
+        while sentinel.lanes.len() < height {
+            sentinel.lanes.push(Lane { next: None, span: 0 });
+        }
+    }
+
+    /// Returns the `Node` at position `pos` (i.e. index `pos - 1`).
+    fn node_at(&self, pos: usize) -> Option<NodeLink<T>> {
+        self.walk_from(&self.predecessors(pos.saturating_add(1)), pos)
+    }
+
+    /// Finds, for each lane (lowest first), the last `Tower` positioned strictly before `pos`, with its position.
+    fn predecessors(&self, pos: usize) -> Vec<(TowerLink<T>, usize)> {
+        let levels = self.sentinel.borrow().lanes.len();
+        let mut update = Vec::with_capacity(levels);
+        let mut tower = Rc::clone(&self.sentinel);
+        let mut tower_pos = 0_usize;
+        for level in (0..levels).rev() {
+            loop {
+                let step = tower.borrow()
+                                .lanes
+                                .get(level)
+                                .and_then(|lane| lane.next.clone().map(|next| (next, lane.span)));
+                match step {
+                    Some((next, span)) if tower_pos.saturating_add(span) < pos => {
+                        tower = next;
+                        tower_pos = tower_pos.saturating_add(span);
+                    },
+                    _ => break,
+                }
+            }
+            update.push((Rc::clone(&tower), tower_pos));
+        }
+        update.reverse();
+        update
+    }
+
+    /// Generates a geometrically distributed `Tower` height (each additional lane with probability 1/2).
+    fn random_height(&mut self) -> usize {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        usize::try_from(self.rng.trailing_ones()).map_or(MAX_LEVELS, |height| height.min(MAX_LEVELS))
+    }
+
+    /// Removes empty lanes from the top of the sentinel.
+    fn shrink(&mut self) {
+        let mut sentinel = self.sentinel.borrow_mut();
+        while sentinel.lanes.last().is_some_and(|lane| lane.next.is_none()) {
+            sentinel.lanes.pop();
+        }
+    }
+
+    /// Walks the base chain from the nearest `Tower` in `update`'s lowest lane to the `Node` at position `pos`.
+    /// Returns `None` for position 0 (the sentinel, which has no `Node`).
+    fn walk_from(&self, update: &[(TowerLink<T>, usize)], pos: usize) -> Option<NodeLink<T>> {
+        let (start, start_pos) = update.first()
+                                       .and_then(|(tower, tower_pos)| {
+                                           tower.borrow()
+                                                .node
+                                                .as_ref()
+                                                .and_then(WeakLink::to_strong)
+                                                .map(|node| (node, *tower_pos))
+                                       })
+                                       .or_else(|| self.list.head_link().map(|head| (head, 1)))?;
+        match pos.checked_sub(start_pos) {
+            Some(steps) => (0..steps).try_fold(start, |node, _| node.borrow().next.clone()),
+            None => None,
+        }
+    }
+}
+
+/// Idiomatic `Default` impl for types with parameterless constructors.
+impl<T> Default for IndexedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read-only access to the underlying `DoublyLinkedList`.  Mutable access is not offered, as it would bypass the
+/// maintenance of the express lanes.
+impl<T> Deref for IndexedList<T> {
+    type Target = DoublyLinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: Debug> Debug for IndexedList<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("IndexedList")
+         .field("list", &self.list)
+         .field("len", &self.len)
+         .field("levels", &self.sentinel.borrow().lanes.len())
+         .finish()
+    }
+}
+
+/// Dismantles the express lanes iteratively, so that dropping a long list does not recurse once per `Tower`.  The base
+/// chain of `Node`s is dismantled by the underlying list's own `Drop`.
+impl<T> Drop for IndexedList<T> {
+    fn drop(&mut self) {
+        let mut tower_opt = self.sentinel
+                                .borrow_mut()
+                                .lanes
+                                .drain(..)
+                                .next()
+                                .and_then(|lane| lane.next);
+        while let Some(tower) = tower_opt {
+            tower_opt = tower.borrow_mut()
+                             .lanes
+                             .drain(..)
+                             .next()
+                             .and_then(|lane| lane.next);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+#![allow(clippy::unwrap_used, clippy::arithmetic_side_effects, clippy::indexing_slicing)]
+use super::*;
+
+fn contents<T: Clone>(list: &IndexedList<T>) -> Vec<T> {
+    list.iter()
+        .map(|link| link.borrow().data.clone())
+        .collect()
+}
+
+/// Verifies that every express lane's links and spans agree with the base chain: each `Tower` in a lane must sit over
+/// the `Node` at the position implied by the accumulated spans, and no `Tower` may refer to a removed `Node`.
+fn assert_lanes_consistent<T>(list: &IndexedList<T>) {
+    let nodes = list.iter().collect::<Vec<_>>();
+    assert_eq!(nodes.len(), list.len());
+    let levels = list.sentinel.borrow().lanes.len();
+    for level in 0..levels {
+        let mut tower = Rc::clone(&list.sentinel);
+        let mut pos = 0;
+        loop {
+            let step = tower.borrow().lanes[level].next.clone().map(|next| (next, tower.borrow().lanes[level].span));
+            match step {
+                Some((next, span)) => {
+                    assert!(span > 0, "lane {} has a zero span at position {}", level, pos);
+                    pos += span;
+                    let node = next.borrow().node.as_ref().and_then(WeakLink::to_strong).unwrap();
+                    assert!(Rc::ptr_eq(&node, &nodes[pos - 1]), "lane {} is misaligned at position {}", level, pos);
+                    tower = next;
+                },
+                None => break,
+            }
+        }
+    }
+}
+
+/// Deterministic pseudo-random sequence for exercising the list against a `Vec` model.
+fn pseudo_random(state: &mut u64) -> usize {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    usize::try_from(*state % 1_000_003).unwrap()
+}
+
+#[test]
+fn get_returns_the_node_at_each_index() {
+    // given a list of 500 elements
+    let mut sut = IndexedList::new();
+    (0..500).for_each(|datum| { sut.push_back(datum); });
+
+    // when each index is accessed
+    let result = (0..500).map(|index| sut.get(index).unwrap().borrow().data).collect::<Vec<_>>();
+
+    // then each element is found at its index
+    assert_eq!(result, (0..500).collect::<Vec<_>>());
+
+    // and indices beyond the end yield nothing
+    assert!(sut.get(500).is_none());
+}
+
+#[test]
+fn push_builds_express_lanes_over_the_base_chain() {
+    // given an empty list
+    let mut sut = IndexedList::new();
+
+    // when many elements are pushed at either end
+    (0..1000).for_each(|datum| { match datum % 2 {
+        0 => sut.push_back(datum),
+        _ => sut.push_front(datum),
+    }; });
+
+    // then multiple express lanes exist
+    assert!(sut.sentinel.borrow().lanes.len() > 4);
+
+    // and they are consistent with the base chain
+    assert_lanes_consistent(&sut);
+}
+
+#[test]
+fn insert_at_and_remove_at_agree_with_a_vec_model() {
+    // given an empty list and an equivalent `Vec`
+    let mut sut = IndexedList::new();
+    let mut model = Vec::new();
+    let mut rng = 42_u64;
+
+    // when a random mix of insertions and removals is applied to both
+    for datum in 0..2000 {
+        match pseudo_random(&mut rng) % 3 {
+            0 if !model.is_empty() => {
+                let index = pseudo_random(&mut rng) % model.len();
+                assert_eq!(sut.remove_at(index), Ok(model.remove(index)));
+            },
+            _ => {
+                let index = pseudo_random(&mut rng) % (model.len() + 1);
+                sut.insert_at(index, datum).unwrap();
+                model.insert(index, datum);
+            },
+        }
+    }
+
+    // then the list matches the model
+    assert_eq!(sut.len(), model.len());
+    assert_eq!(contents(&sut), model);
+    assert!((0..model.len()).all(|index| sut.get(index).unwrap().borrow().data == model[index]));
+
+    // and the express lanes are consistent with the base chain
+    assert_lanes_consistent(&sut);
+}
+
+#[test]
+fn removing_every_element_leaves_no_express_lanes() {
+    // given a list of 200 elements
+    let mut sut = IndexedList::new();
+    (0..200).for_each(|datum| { sut.push_back(datum); });
+
+    // when every element is popped from alternating ends
+    let result = (0..200).map(|count| match count % 2 {
+                                 0 => sut.pop_front(),
+                                 _ => sut.pop_back(),
+                             })
+                             .collect::<Result<Vec<_>>>();
+
+    // then every element is returned
+    assert_eq!(result.map(|data| data.len()), Ok(200));
+
+    // and the list is empty with no express lanes remaining
+    assert!(sut.is_empty());
+    assert!(sut.sentinel.borrow().lanes.is_empty());
+    assert_eq!(sut.pop_back(), Err(Error::EmptyList { op: Operation::PopBack }));
+}
+
+#[test]
+fn insert_at_beyond_the_end_yields_an_error() {
+    // given a list of two elements
+    let mut sut = IndexedList::new();
+    sut.push_back(1).push_back(2);
+
+    // when insert_at() is called beyond the end
+    let result = sut.insert_at(3, 3).map(|_| ());
+
+    // then the insertion is rejected
+    assert_eq!(result, Err(Error::IndexOutOfBounds { op: Operation::InsertAt, index: 3, len: 2 }));
+    assert_eq!(contents(&sut), vec![1, 2]);
+}
+
+#[test]
+fn remove_at_of_a_node_with_a_live_reference_leaves_the_list_unmodified() {
+    // given a list of 100 elements and a live reference to one of them
+    let mut sut = IndexedList::new();
+    (0..100).for_each(|datum| { sut.push_back(datum); });
+    let live_ref = sut.get(50);
+
+    // when remove_at() is called for that element
+    let result = sut.remove_at(50);
+
+    // then the removal is rejected
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::RemoveAt, index: 50, .. })));
+
+    // and the list and its express lanes are unmodified
+    assert_eq!(contents(&sut), (0..100).collect::<Vec<_>>());
+    assert_lanes_consistent(&sut);
+    drop(live_ref);
+    assert_eq!(sut.remove_at(50), Ok(50));
+}
@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    DoublyLinkedList,
+    NodeLink,
+};
+use alloc::boxed::Box;
+use core::{
+    cell::{
+        Ref,
+        RefMut,
+    },
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    hash::Hash,
+};
+use std::collections::HashMap;
+
+/// The entries accessed a given number of times, from most to least recently used.
+#[derive(Debug)]
+struct Bucket<K, V> {
+    frequency: usize,
+    entries: DoublyLinkedList<(K, V)>,
+}
+
+impl<K, V> Bucket<K, V> {
+    fn new(frequency: usize) -> Self {
+        Self {
+            frequency,
+            entries: DoublyLinkedList::new(),
+        }
+    }
+}
+
+/// Locates an entry: its `Node`, and the `Node` of the `Bucket` holding it.
+struct Slot<K, V> {
+    bucket: NodeLink<Bucket<K, V>>,
+    node: NodeLink<(K, V)>,
+}
+
+type BucketLink<K, V> = NodeLink<Bucket<K, V>>;
+
+/// An entry's map key, the entry itself, and the bucket which held it.
+type Unlinked<K, V> = (K, (K, V), BucketLink<K, V>);
+
+/// `LfuCache` maps keys to values, holding at most `capacity` entries.  When an insertion would exceed the cache's
+/// capacity, the least frequently used entry is evicted (the least recently used of them, should several tie) and
+/// passed to the eviction callback, if one is set.  Entries are held in a `DoublyLinkedList` of frequency buckets, in
+/// ascending order of access count, each itself a `DoublyLinkedList` of entries ordered by recency.  A `HashMap`
+/// locates each entry and its bucket, so lookups, which move an entry to the next bucket, and evictions are all O(1).
+/// An entry's access count starts at 1 when it is inserted.  Requires the `std` feature.
+pub struct LfuCache<K, V> {
+    map: HashMap<K, Slot<K, V>>,
+    buckets: DoublyLinkedList<Bucket<K, V>>,
+    capacity: usize,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K: Clone + Eq + Hash, V> LfuCache<K, V> {
+    /// Constructor for a cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            buckets: DoublyLinkedList::new(),
+            capacity,
+            on_evict: None,
+        }
+    }
+
+    /// The maximum number of entries the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Predicate revealing whether the cache holds an entry for `key`.  Does not count as an access.
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// The number of times the entry for `key` has been accessed, counting its insertion.
+    pub fn frequency(&self, key: &K) -> Option<usize> {
+        self.map
+            .get(key)
+            .map(|slot| slot.bucket.borrow().data.frequency)
+    }
+
+    /// Returns the value for `key`, counting an access.
+    pub fn get(&mut self, key: &K) -> Option<Ref<'_, V>> {
+        let slot = self.access(key)?;
+        Some(Ref::map(slot.node.borrow(), |node| &node.data.1))
+    }
+
+    /// Returns the value for `key` mutably, counting an access.
+    pub fn get_mut(&mut self, key: &K) -> Option<RefMut<'_, V>> {
+        let slot = self.access(key)?;
+        Some(RefMut::map(slot.node.borrow_mut(), |node| &mut node.data.1))
+    }
+
+    /// Predicate revealing whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The number of entries held by the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the value for `key` without counting an access.
+    pub fn peek(&self, key: &K) -> Option<Ref<'_, V>> {
+        self.map
+            .get(key)
+            .map(|slot| Ref::map(slot.node.borrow(), |node| &node.data.1))
+    }
+
+    /// Removes the entry which would be evicted next and returns its key and value.  The eviction callback is not
+    /// invoked.
+    pub fn pop_lfu(&mut self) -> Option<(K, V)> {
+        let key = self.buckets
+                      .head_link()?
+                      .borrow()
+                      .data
+                      .entries
+                      .tail_link()?
+                      .borrow()
+                      .data
+                      .0
+                      .clone();
+        self.remove_entry(&key)
+    }
+
+    /// Inserts `value` for `key`, returning the value it replaced, if any.  Replacing a value counts as an access; a
+    /// new entry is first made room for by evicting the least frequently used entry, should the cache be full.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(slot) = self.access(&key) {
+            return Some(core::mem::replace(&mut slot.node.borrow_mut().data.1, value));
+        }
+        match self.capacity {
+            0 => self.report(key, value),
+            capacity => {
+                while self.map.len() >= capacity {
+                    match self.pop_lfu() {
+                        Some((key, value)) => self.report(key, value),
+                        None => break,
+                    }
+                }
+                let first = self.buckets
+                                .head_link()
+                                .filter(|bucket| bucket.borrow().data.frequency == 1);
+                let bucket = match first {
+                    Some(bucket) => bucket,
+                    None => self.buckets.push_front_link(Bucket::new(1)),
+                };
+                self.link(key.clone(), (key, value), bucket);
+            },
+        }
+        None
+    }
+
+    /// Removes the entry for `key` and returns its value.  The eviction callback is not invoked.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Changes the cache's capacity to `capacity`, evicting least frequently used entries until it fits.
+    pub fn resize(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = capacity;
+        while self.map.len() > self.capacity {
+            match self.pop_lfu() {
+                Some((key, value)) => self.report(key, value),
+                None => break,
+            }
+        }
+        self
+    }
+
+    /// Registers `callback` to receive the key and value of every entry subsequently evicted to honor the cache's
+    /// capacity.  Entries removed explicitly (by `pop_lfu()` or `remove()`) or replaced by `put()` are not reported.
+    pub fn set_eviction_callback<F>(&mut self, callback: F) -> &mut Self where F: FnMut(K, V) + 'static {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    /// Counts an access to the entry for `key`, relinking its `Node` to the front of the next frequency's bucket
+    /// (creating that bucket if necessary, and discarding its former bucket if thereby emptied).  Returns the entry's
+    /// `Slot`.
+    fn access(&mut self, key: &K) -> Option<&Slot<K, V>> {
+        let slot = self.map.get_mut(key)?;
+        let bucket = slot.bucket.clone();
+        let frequency = bucket.borrow().data.frequency.saturating_add(1);
+        let saturated = bucket.borrow().data.frequency == frequency;
+        match saturated {
+            // The access count can rise no further, so the entry stays in its bucket
+            true => {
+                bucket.borrow_mut()
+                      .data
+                      .entries
+                      .move_to_front(&slot.node)
+                      .expect(msg::ERR_INTERNAL_NODE_NOT_IN_LIST);
+            },
+            false => {
+                let next = bucket.borrow().next.clone();
+                let target = match next {
+                    Some(next) if next.borrow().data.frequency == frequency => next,
+                    _ => self.buckets.insert_after_link(bucket.clone(), Bucket::new(frequency)),
+                };
+                bucket.borrow_mut()
+                      .data
+                      .entries
+                      .transfer_to_front(&slot.node, &mut target.borrow_mut().data.entries)
+                      .expect(msg::ERR_INTERNAL_NODE_NOT_IN_LIST);
+                slot.bucket = target;
+                self.discard_if_empty(bucket);
+            },
+        }
+        self.map.get(key)
+    }
+
+    /// Removes `bucket` from the list of buckets if it holds no entries.
+    fn discard_if_empty(&mut self, bucket: BucketLink<K, V>) {
+        if bucket.borrow().data.entries.head_link().is_none() {
+            // No `Slot` refers to an empty bucket, so the removal cannot be blocked
+            self.buckets
+                .remove(bucket)
+                .expect(msg::ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES);
+        }
+    }
+
+    /// Inserts `entry` at the front of `bucket`, and maps `key` to it.
+    fn link(&mut self, key: K, entry: (K, V), bucket: BucketLink<K, V>) -> &Slot<K, V> {
+        let node = bucket.borrow_mut().data.entries.push_front_link(entry);
+        self.map.entry(key).or_insert(Slot { bucket, node })
+    }
+
+    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        let (_, entry, bucket) = self.unlink(key)?;
+        self.discard_if_empty(bucket);
+        Some(entry)
+    }
+
+    fn report(&mut self, key: K, value: V) {
+        if let Some(callback) = self.on_evict.as_mut() {
+            callback(key, value);
+        }
+    }
+
+    /// Removes the entry for `key` from the map and from its bucket, returning the key, the entry and the bucket.
+    fn unlink(&mut self, key: &K) -> Option<Unlinked<K, V>> {
+        let (key, Slot { bucket, node }) = self.map.remove_entry(key)?;
+        let entry = bucket.borrow_mut()
+                          .data
+                          .entries
+                          .remove(node)
+                          .expect(msg::ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES);
+        Some((key, entry, bucket))
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for LfuCache<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LfuCache")
+         .field("buckets", &self.buckets)
+         .field("capacity", &self.capacity)
+         .finish()
+    }
+}
@@ -0,0 +1,197 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+/// Returns each frequency bucket in ascending order, with its keys from most to least recently used.
+fn buckets<V>(cache: &LfuCache<&'static str, V>) -> Vec<(usize, Vec<&'static str>)> {
+    cache.buckets
+         .iter()
+         .map(|bucket| {
+             let bucket = bucket.borrow();
+             (bucket.data.frequency, bucket.data.entries.iter().map(|entry| entry.borrow().data.0).collect())
+         })
+         .collect()
+}
+
+fn cache_of(keys: &[&'static str], capacity: usize) -> LfuCache<&'static str, i32> {
+    let mut cache = LfuCache::new(capacity);
+    keys.iter().zip(1..).for_each(|(&key, value)| { cache.put(key, value); });
+    cache
+}
+
+#[test]
+fn put_inserts_entries_into_the_first_frequency_bucket() {
+    // given an empty cache
+    let mut sut = LfuCache::new(3);
+
+    // when entries are put
+    let result = (sut.put("a", 1), sut.put("b", 2));
+
+    // then no values are replaced, and the entries are held with a frequency of 1, most recent first
+    assert_eq!(result, (None, None));
+    assert_eq!(buckets(&sut), vec![(1, vec!["b", "a"])]);
+    assert_eq!(sut.frequency(&"a"), Some(1));
+}
+
+#[test]
+fn get_moves_entries_between_buckets_and_discards_emptied_buckets() {
+    // given a cache holding two entries
+    let mut sut = cache_of(&["a", "b"], 3);
+
+    // when one entry is read twice, and the other once
+    let result = (sut.get(&"a").map(|value| *value),
+                  sut.get(&"a").map(|value| *value),
+                  sut.get(&"b").map(|value| *value));
+
+    // then the values are returned
+    assert_eq!(result, (Some(1), Some(1), Some(2)));
+
+    // and each entry is held in the bucket for its access count, with the emptied bucket discarded
+    assert_eq!(buckets(&sut), vec![(2, vec!["b"]), (3, vec!["a"])]);
+}
+
+#[test]
+fn get_relinks_an_entry_between_buckets_without_reallocating_it() {
+    // given a cache holding an entry, and that entry's `Node`
+    let mut sut = cache_of(&["a", "b"], 3);
+    let node = sut.map.get(&"a").map(|slot| slot.node.clone()).unwrap();
+
+    // when the entry is read twice
+    let _ = sut.get(&"a").map(|value| *value);
+    let _ = sut.get(&"a").map(|value| *value);
+
+    // then the entry is still held by the same `Node`, now in the bucket for its access count
+    assert!(Rc::ptr_eq(&sut.map.get(&"a").unwrap().node, &node));
+    assert_eq!(buckets(&sut), vec![(1, vec!["b"]), (3, vec!["a"])]);
+}
+
+#[test]
+fn put_beyond_capacity_evicts_the_least_frequently_used_entry() {
+    // given a full cache, one of whose entries has been read
+    let mut sut = cache_of(&["a", "b"], 2);
+    sut.get(&"a");
+
+    // when another entry is put
+    sut.put("c", 3);
+
+    // then the least frequently used entry is evicted
+    assert!(!sut.contains(&"b"));
+    assert_eq!(buckets(&sut), vec![(1, vec!["c"]), (2, vec!["a"])]);
+}
+
+#[test]
+fn eviction_breaks_frequency_ties_by_recency() {
+    // given a full cache whose entries have all been read once, in order
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = cache_of(&["a", "b", "c"], 3);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    ["b", "a", "c"].iter().for_each(|key| { sut.get(key); });
+
+    // when entries are put
+    sut.put("d", 4);
+    sut.put("e", 5);
+
+    // then the tied entries are evicted least recently used first
+    assert_eq!(*evicted.borrow(), vec![("b", 2), ("d", 4)]);
+    assert_eq!(buckets(&sut), vec![(1, vec!["e"]), (2, vec!["c", "a"])]);
+}
+
+#[test]
+fn frequently_used_entries_survive_a_scan() {
+    // given a cache whose hot entries have been read repeatedly
+    let mut sut = cache_of(&["hot1", "hot2"], 3);
+    (0..3).for_each(|_| { sut.get(&"hot1"); sut.get(&"hot2"); });
+
+    // when a scan puts many entries once each
+    ["s1", "s2", "s3", "s4", "s5"].iter().for_each(|&key| { sut.put(key, 0); });
+
+    // then the hot entries are retained
+    assert!(sut.contains(&"hot1") && sut.contains(&"hot2"));
+    assert_eq!(buckets(&sut), vec![(1, vec!["s5"]), (4, vec!["hot2", "hot1"])]);
+}
+
+#[test]
+fn put_of_an_existing_key_replaces_its_value_and_counts_an_access() {
+    // given a cache holding an entry
+    let mut sut = cache_of(&["a"], 2);
+
+    // when the key is put again, and its value updated in place
+    let result = sut.put("a", 10);
+    *sut.get_mut(&"a").unwrap() += 1;
+
+    // then the previous value is returned, and each counted as an access
+    assert_eq!(result, Some(1));
+    assert_eq!(sut.peek(&"a").map(|value| *value), Some(11));
+    assert_eq!(sut.frequency(&"a"), Some(3));
+}
+
+#[test]
+fn peek_does_not_count_an_access() {
+    // given a cache holding an entry
+    let sut = cache_of(&["a"], 1);
+
+    // when it is peeked at
+    let result = sut.peek(&"a").map(|value| *value);
+
+    // then its frequency is unchanged
+    assert_eq!(result, Some(1));
+    assert_eq!(sut.frequency(&"a"), Some(1));
+}
+
+#[test]
+fn pop_lfu_and_remove_return_entries_without_invoking_the_eviction_callback() {
+    // given a cache with an eviction callback, one of whose entries has been read
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = cache_of(&["a", "b", "c"], 3);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    sut.get(&"a");
+
+    // when entries are removed explicitly
+    let result = (sut.pop_lfu(), sut.remove(&"a"), sut.remove(&"a"));
+
+    // then the entries are returned, and their emptied buckets discarded
+    assert_eq!(result, (Some(("b", 2)), Some(1), None));
+    assert_eq!(buckets(&sut), vec![(1, vec!["c"])]);
+
+    // and the callback was not invoked
+    assert!(evicted.borrow().is_empty());
+}
+
+#[test]
+fn resize_evicts_least_frequently_used_entries() {
+    // given a full cache with entries of differing frequencies
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = cache_of(&["a", "b", "c"], 3);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    sut.get(&"a");
+
+    // when the cache is shrunk
+    sut.resize(1);
+
+    // then the least frequently used entries are evicted
+    assert_eq!(*evicted.borrow(), vec![("b", 2), ("c", 3)]);
+    assert_eq!(sut.capacity(), 1);
+    assert_eq!(sut.len(), 1);
+}
+
+#[test]
+fn zero_capacity_cache_evicts_every_entry() {
+    // given a cache with no capacity and an eviction callback
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = LfuCache::new(0);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+
+    // when an entry is put
+    sut.put("a", 1);
+
+    // then it is evicted immediately
+    assert!(sut.is_empty());
+    assert_eq!(*evicted.borrow(), vec![("a", 1)]);
+}
@@ -6,22 +6,91 @@
 //#![warn(clippy::multiple_crate_versions, clippy::print_stdout, clippy::unimplemented, clippy::use_debug)]
 // vvv Safety-critical application lints (pedantic: use for safety-critical applications only) vvv
 #![deny(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_precision_loss,
-        clippy::cast_sign_loss, clippy::float_cmp_const, clippy::indexing_slicing, clippy::integer_arithmetic,
-        clippy::maybe_infinite_iter, clippy::option_unwrap_used, clippy::result_unwrap_used)]
+        clippy::cast_sign_loss, clippy::float_cmp_const, clippy::indexing_slicing, clippy::arithmetic_side_effects,
+        clippy::maybe_infinite_iter, clippy::unwrap_used)]
 // ^^^ End of safety-critical lint section ^^^
 #![allow(clippy::match_bool,)]
+// `std` is a default feature; without it, the crate requires only `core` and `alloc`
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+extern crate alloc;
+mod bounded_list;
+mod circular_list;
 mod consts;
+mod dlx;
 mod error;
+mod indexed_list;
+#[cfg(feature = "std")]
+mod lfu_cache;
+#[cfg(feature = "std")]
+mod linked_hash_map;
+#[cfg(feature = "std")]
+mod linked_hash_set;
+mod locale;
+#[cfg(feature = "std")]
+mod lru_cache;
 mod node;
+mod sorted_list;
+mod timing_wheel;
 mod doubly_linked_list;
 pub use self::{
-    doubly_linked_list::DoublyLinkedList,
-    error::Error,
+    bounded_list::{
+        BoundedList,
+        EvictionPolicy,
+        Rejected,
+    },
+    circular_list::{
+        CircularList,
+        RingCursor,
+    },
+    dlx::{
+        Dlx,
+        Solutions,
+    },
+    doubly_linked_list::{
+        AllocationStats,
+        DoublyLinkedList,
+        Iter,
+        ListStats,
+        LiveIter,
+        Mutation,
+        ObserverId,
+        Range,
+        RefCounts,
+        SearchFrom,
+        Transaction,
+    },
+    error::{
+        Error,
+        Operation,
+    },
+    indexed_list::IndexedList,
+    locale::Locale,
     node::{
         Node,
         NodeLink,
     },
+    sorted_list::SortedList,
+    timing_wheel::{
+        TimerHandle,
+        TimingWheel,
+    },
+};
+#[cfg(feature = "std")]
+pub use self::{
+    lfu_cache::LfuCache,
+    linked_hash_map::{
+        Entries,
+        EntryOrder,
+        EntryRef,
+        LinkedHashMap,
+    },
+    linked_hash_set::{
+        ElementRef,
+        Elements,
+        LinkedHashSet,
+    },
+    lru_cache::LruCache,
 };
 use self::node::WeakLink;
-use std::result::Result as StdResult;
+use core::result::Result as StdResult;
 pub type Result<T> = StdResult<T, Error>;
@@ -0,0 +1,300 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    DoublyLinkedList,
+    NodeLink,
+    WeakLink,
+};
+use core::{
+    cell::{
+        Ref,
+        RefMut,
+    },
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    hash::Hash,
+    marker::PhantomData,
+};
+use std::collections::HashMap;
+
+/// Determines the order in which a `LinkedHashMap` holds its entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EntryOrder {
+    /// Entries are held in the order their keys were first inserted; replacing a value does not reorder its entry.
+    Insertion,
+    /// Entries are held from least to most recently accessed; `get()`, `get_mut()` and `insert()` move the entry to the
+    /// back.
+    Access,
+}
+
+/// `LinkedHashMap` maps keys to values, iterating over its entries in a deterministic order (see `EntryOrder`).  A
+/// `HashMap` locates each entry's `Node` in a `DoublyLinkedList` holding the entries in order, so insertion, removal,
+/// lookup and removal from either end are all O(1).  As with `HashMap`, lookups accept any borrowed form of the key.
+/// Requires the `std` feature.
+pub struct LinkedHashMap<K, V> {
+    map: HashMap<K, NodeLink<(K, V)>>,
+    entries: DoublyLinkedList<(K, V)>,
+    order: EntryOrder,
+}
+
+impl<K: Clone + Eq + Hash, V> LinkedHashMap<K, V> {
+    /// Constructor for an empty, insertion-ordered map.
+    pub fn new() -> Self {
+        Self::with_order(EntryOrder::Insertion)
+    }
+
+    /// Constructor for an empty map holding its entries in `order`.
+    pub fn with_order(order: EntryOrder) -> Self {
+        Self {
+            map: HashMap::new(),
+            entries: DoublyLinkedList::new(),
+            order,
+        }
+    }
+
+    /// Returns the last entry.
+    pub fn back(&self) -> Option<EntryRef<'_, K, V>> {
+        self.entries.tail_link().map(EntryRef::new)
+    }
+
+    /// Predicate revealing whether the map holds an entry for `key`.  Does not count as an access.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the first entry.
+    pub fn front(&self) -> Option<EntryRef<'_, K, V>> {
+        self.entries.head_link().map(EntryRef::new)
+    }
+
+    /// Returns the value for `key`.  Under `EntryOrder::Access`, the entry is moved to the back.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<Ref<'_, V>> where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        let node = self.access(key)?;
+        Some(Ref::map(node.borrow(), |node| &node.data.1))
+    }
+
+    /// Returns the value for `key` mutably.  Under `EntryOrder::Access`, the entry is moved to the back.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<RefMut<'_, V>>
+                     where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        let node = self.access(key)?;
+        Some(RefMut::map(node.borrow_mut(), |node| &mut node.data.1))
+    }
+
+    /// Inserts `value` for `key`, returning the value it replaced, if any.  A new entry is appended at the back; an
+    /// existing entry keeps its position, unless the map is ordered by `EntryOrder::Access`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.access(&key) {
+            Some(node) => Some(core::mem::replace(&mut node.borrow_mut().data.1, value)),
+            None => {
+                let tail = self.entries.push_back_link((key.clone(), value));
+                self.map.insert(key, tail);
+                None
+            },
+        }
+    }
+
+    /// Inserts `value` for `key`, returning the value it replaced, if any.  A new entry is prepended at the front; an
+    /// existing entry keeps its position, unless the map is ordered by `EntryOrder::Access`.
+    pub fn insert_front(&mut self, key: K, value: V) -> Option<V> {
+        match self.access(&key) {
+            Some(node) => Some(core::mem::replace(&mut node.borrow_mut().data.1, value)),
+            None => {
+                let head = self.entries.push_front_link((key.clone(), value));
+                self.map.insert(key, head);
+                None
+            },
+        }
+    }
+
+    /// Predicate revealing whether the map holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Creates a double-ended `Iterator` over the map's entries, in order.  Use `.rev()` to iterate from the back.
+    pub fn iter(&self) -> Entries<'_, K, V> {
+        Entries {
+            front: self.entries.head_link(),
+            back: self.entries.tail_link(),
+            len: self.map.len(),
+            entries: PhantomData,
+        }
+    }
+
+    /// The number of entries held by the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Moves the entry for `key` to the back.  Returns `false` if the map holds no such entry.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        match self.map.get(key) {
+            Some(node) => {
+                self.entries
+                    .move_to_back(node)
+                    .expect(msg::ERR_INTERNAL_NODE_NOT_IN_LIST);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// The order in which the map holds its entries.
+    pub fn order(&self) -> EntryOrder {
+        self.order
+    }
+
+    /// Returns the value for `key` without counting as an access.
+    pub fn peek<Q>(&self, key: &Q) -> Option<Ref<'_, V>> where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        self.map
+            .get(key)
+            .map(|node| Ref::map(node.borrow(), |node| &node.data.1))
+    }
+
+    /// Removes the last entry and returns its key and value.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let key = self.entries.tail_link()?.borrow().data.0.clone();
+        self.remove_entry(&key)
+    }
+
+    /// Removes the first entry and returns its key and value.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let key = self.entries.head_link()?.borrow().data.0.clone();
+        self.remove_entry(&key)
+    }
+
+    /// Removes the entry for `key` and returns its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V> where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Returns the `Node` for `key`, first moving it to the back if the map is ordered by `EntryOrder::Access`.
+    fn access<Q>(&mut self, key: &Q) -> Option<&NodeLink<(K, V)>>
+                where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        let node = self.map.get(key)?;
+        if self.order == EntryOrder::Access {
+            self.entries
+                .move_to_back(node)
+                .expect(msg::ERR_INTERNAL_NODE_NOT_IN_LIST);
+        }
+        Some(node)
+    }
+
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)> where K: core::borrow::Borrow<Q>, Q: ?Sized + Eq + Hash {
+        let node = self.map.remove(key)?;
+        Some(self.entries
+                 .remove(node)
+                 .expect(msg::ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES))
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Debug + Eq + Hash, V: Debug> Debug for LinkedHashMap<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut map = f.debug_map();
+        self.iter().for_each(|entry| { map.entry(&*entry.key(), &*entry.value()); });
+        map.finish()
+    }
+}
+
+/// `EntryRef` refers to one entry of a `LinkedHashMap`, as yielded by `Entries`, `front()` and `back()`.  It borrows
+/// the map, which therefore cannot be modified while it exists.  The entry is reached through its `Node`, so no key
+/// is hashed to produce or to read an `EntryRef`.
+pub struct EntryRef<'a, K, V> {
+    node: NodeLink<(K, V)>,
+    entries: PhantomData<&'a DoublyLinkedList<(K, V)>>,
+}
+
+impl<K, V> EntryRef<'_, K, V> {
+    fn new(node: NodeLink<(K, V)>) -> Self {
+        Self {
+            node,
+            entries: PhantomData,
+        }
+    }
+
+    /// Borrows the entry's key and value.
+    pub fn get(&self) -> Ref<'_, (K, V)> {
+        Ref::map(self.node.borrow(), |node| &node.data)
+    }
+
+    /// Borrows the entry's key.
+    pub fn key(&self) -> Ref<'_, K> {
+        Ref::map(self.node.borrow(), |node| &node.data.0)
+    }
+
+    /// Borrows the entry's value.
+    pub fn value(&self) -> Ref<'_, V> {
+        Ref::map(self.node.borrow(), |node| &node.data.1)
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for EntryRef<'_, K, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&*self.get(), f)
+    }
+}
+
+/// `Entries` iterates over a `LinkedHashMap`'s entries in order, from either end, following the links between their
+/// `Node`s.  It borrows the map, which therefore cannot be modified during iteration.
+pub struct Entries<'a, K, V> {
+    front: Option<NodeLink<(K, V)>>,
+    back: Option<NodeLink<(K, V)>>,
+    len: usize,
+    entries: PhantomData<&'a DoublyLinkedList<(K, V)>>,
+}
+
+impl<'a, K, V> Entries<'a, K, V> {
+    /// Yields the entry held by the `Node` at the front (or back), replacing that `Node` with its neighbour (per
+    /// `advance`).
+    fn take_from<F>(&mut self, advance: F, front: bool) -> Option<EntryRef<'a, K, V>>
+                   where F: FnOnce(&NodeLink<(K, V)>) -> Option<NodeLink<(K, V)>> {
+        self.len = self.len.checked_sub(1)?;
+        let end = match front {
+            true => &mut self.front,
+            false => &mut self.back,
+        };
+        let node = end.take()?;
+        *end = advance(&node);
+        Some(EntryRef::new(node))
+    }
+}
+
+impl<'a, K, V> Iterator for Entries<'a, K, V> {
+    type Item = EntryRef<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.take_from(|node| node.borrow().next.clone(), true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Entries<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.take_from(|node| node.borrow().prev.as_ref().and_then(WeakLink::to_strong), false)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Entries<'_, K, V> {}
+
+impl<'a, K: Debug, V: Debug> Debug for Entries<'a, K, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("Entries")
+         .field("front", &self.front)
+         .field("back", &self.back)
+         .field("len", &self.len)
+         .finish()
+    }
+}
@@ -0,0 +1,185 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use std::{
+    cell::Cell,
+    hash::Hasher,
+    rc::Rc,
+};
+
+fn map_of(entries: &[(&'static str, i32)], order: EntryOrder) -> LinkedHashMap<&'static str, i32> {
+    let mut map = LinkedHashMap::with_order(order);
+    entries.iter().for_each(|&(key, value)| { map.insert(key, value); });
+    map
+}
+
+fn contents(map: &LinkedHashMap<&'static str, i32>) -> Vec<(&'static str, i32)> {
+    map.iter().map(|entry| *entry.get()).collect()
+}
+
+#[test]
+fn iteration_follows_insertion_order_in_both_directions() {
+    // given a map whose keys were inserted out of hash order
+    let sut = map_of(&[("c", 3), ("a", 1), ("b", 2)], EntryOrder::Insertion);
+
+    // when it is iterated from each end
+    let result = (contents(&sut), sut.iter().rev().map(|entry| *entry.key()).collect::<Vec<_>>());
+
+    // then entries are yielded in insertion order, and its reverse
+    assert_eq!(result, (vec![("c", 3), ("a", 1), ("b", 2)], vec!["b", "a", "c"]));
+    assert_eq!(sut.iter().len(), 3);
+}
+
+#[test]
+fn iteration_from_both_ends_meets_in_the_middle() {
+    // given a map of three entries
+    let sut = map_of(&[("a", 1), ("b", 2), ("c", 3)], EntryOrder::Insertion);
+
+    // when it is iterated alternately from each end
+    let mut entries = sut.iter();
+    let result = [entries.next(), entries.next_back(), entries.next(), entries.next_back(), entries.next()]
+                     .iter()
+                     .map(|entry| entry.as_ref().map(|entry| *entry.key()))
+                     .collect::<Vec<_>>();
+
+    // then each entry is yielded once
+    assert_eq!(result, vec![Some("a"), Some("c"), Some("b"), None, None]);
+}
+
+#[test]
+fn insert_of_an_existing_key_replaces_its_value_in_place() {
+    // given an insertion-ordered map
+    let mut sut = map_of(&[("a", 1), ("b", 2)], EntryOrder::Insertion);
+
+    // when an existing key is inserted and read
+    let result = (sut.insert("a", 10), sut.get(&"a").map(|value| *value));
+
+    // then the previous value is returned, and the entry keeps its position
+    assert_eq!(result, (Some(1), Some(10)));
+    assert_eq!(contents(&sut), vec![("a", 10), ("b", 2)]);
+}
+
+#[test]
+fn access_order_moves_accessed_entries_to_the_back() {
+    // given an access-ordered map
+    let mut sut = map_of(&[("a", 1), ("b", 2), ("c", 3)], EntryOrder::Access);
+
+    // when entries are read, updated and re-inserted
+    assert_eq!(sut.get(&"a").map(|value| *value), Some(1));
+    *sut.get_mut(&"b").unwrap() += 10;
+    sut.insert("c", 30);
+    sut.get(&"a");
+
+    // then each access moves its entry to the back
+    assert_eq!(contents(&sut), vec![("b", 12), ("c", 30), ("a", 1)]);
+
+    // and peeking does not count as an access
+    assert_eq!(sut.peek(&"b").map(|value| *value), Some(12));
+    assert_eq!(sut.front().map(|entry| *entry.key()), Some("b"));
+}
+
+#[test]
+fn front_back_and_pops_reach_both_ends() {
+    // given a map of three entries
+    let mut sut = map_of(&[("a", 1), ("b", 2), ("c", 3)], EntryOrder::Insertion);
+
+    // when its ends are inspected and removed
+    let ends = (sut.front().map(|entry| *entry.get()), sut.back().map(|entry| *entry.get()));
+    let popped = (sut.pop_front(), sut.pop_back());
+
+    // then the first and last entries are returned
+    assert_eq!(ends, (Some(("a", 1)), Some(("c", 3))));
+    assert_eq!(popped, (Some(("a", 1)), Some(("c", 3))));
+
+    // and only the middle entry remains
+    assert_eq!(contents(&sut), vec![("b", 2)]);
+    assert!(!sut.contains_key(&"a"));
+}
+
+#[test]
+fn remove_unlinks_the_entry_from_the_order() {
+    // given a map of three entries
+    let mut sut = map_of(&[("a", 1), ("b", 2), ("c", 3)], EntryOrder::Insertion);
+
+    // when the middle entry is removed, twice
+    let result = (sut.remove(&"b"), sut.remove(&"b"));
+
+    // then it is removed once
+    assert_eq!(result, (Some(2), None));
+    assert_eq!(contents(&sut), vec![("a", 1), ("c", 3)]);
+    assert_eq!(sut.len(), 2);
+
+    // and a re-inserted key is appended at the back
+    sut.insert("b", 20);
+    assert_eq!(contents(&sut), vec![("a", 1), ("c", 3), ("b", 20)]);
+}
+
+#[test]
+fn empty_map_yields_nothing() {
+    // given an empty map
+    let mut sut = LinkedHashMap::<&'static str, i32>::default();
+
+    // when its entries are sought
+    let result = (sut.iter().next().map(|entry| *entry.get()), sut.front().map(|entry| *entry.get()), sut.pop_back());
+
+    // then there are none
+    assert_eq!(result, (None, None, None));
+    assert!(sut.is_empty());
+    assert_eq!(sut.order(), EntryOrder::Insertion);
+}
+
+#[test]
+fn debug_formats_entries_in_order() {
+    // given a map
+    let sut = map_of(&[("b", 2), ("a", 1)], EntryOrder::Insertion);
+
+    // when it is formatted
+    let result = format!("{:?}", sut);
+
+    // then its entries appear in order
+    assert_eq!(result, r#"{"b": 2, "a": 1}"#);
+}
+
+#[test]
+fn insert_front_and_move_to_back_reorder_entries() {
+    // given an insertion-ordered map
+    let mut sut = map_of(&[("b", 2), ("c", 3)], EntryOrder::Insertion);
+
+    // when an entry is inserted at the front, and another moved to the back
+    let result = (sut.insert_front("a", 1), sut.move_to_back(&"b"), sut.move_to_back(&"z"));
+
+    // then the entries are reordered
+    assert_eq!(result, (None, true, false));
+    assert_eq!(contents(&sut), vec![("a", 1), ("c", 3), ("b", 2)]);
+}
+
+/// A key which counts how often it is hashed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CountedKey(i32, Rc<Cell<usize>>);
+
+impl Hash for CountedKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.set(self.1.get().saturating_add(1));
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn reading_entries_in_order_hashes_no_keys() {
+    // given a map whose keys count how often they are hashed
+    let hashes = Rc::new(Cell::new(0));
+    let mut sut = LinkedHashMap::new();
+    (0..3).for_each(|key| { sut.insert(CountedKey(key, Rc::clone(&hashes)), ()); });
+    hashes.set(0);
+
+    // when its entries are iterated from both ends, its ends are read and it is formatted
+    let forward = sut.iter().map(|entry| entry.key().0).collect::<Vec<_>>();
+    let backward = sut.iter().rev().map(|entry| entry.key().0).collect::<Vec<_>>();
+    let ends = (sut.front().map(|entry| entry.key().0), sut.back().map(|entry| entry.key().0));
+    let _ = format!("{:?}", sut);
+
+    // then the entries are read in order
+    assert_eq!((forward, backward, ends), (vec![0, 1, 2], vec![2, 1, 0], (Some(0), Some(2))));
+
+    // and no key was hashed
+    assert_eq!(hashes.get(), 0);
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    Entries,
+    EntryRef,
+    LinkedHashMap,
+};
+use alloc::rc::Rc;
+use core::{
+    cell::Ref,
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    hash::Hash,
+};
+
+/// `LinkedHashSet` holds distinct values in a deterministic order: the order of insertion, as adjusted by
+/// `insert_front()` and `move_to_back()`.  It is a `LinkedHashMap` whose values are `()`, so insertion, removal and
+/// membership tests are all O(1).  Set operations yield their elements in the order of the left operand.  Requires the
+/// `std` feature.  Each value is held once, behind an `Rc` which keys the underlying map; the sets returned by set
+/// operations share their values with their operands rather than cloning them.
+pub struct LinkedHashSet<T> {
+    map: LinkedHashMap<Rc<T>, ()>,
+}
+
+impl<T: Eq + Hash> LinkedHashSet<T> {
+    /// Constructor for an empty set.
+    pub fn new() -> Self {
+        Self {
+            map: LinkedHashMap::new(),
+        }
+    }
+
+    /// Predicate revealing whether the set holds `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a new set of the values in this set which are not in `other`, in this set's order.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.filtered(|value| !other.contains(value))
+    }
+
+    /// Appends `value` to the set.  Returns `false` (leaving the set unchanged) if the set already holds `value`.
+    pub fn insert(&mut self, value: T) -> bool {
+        !self.contains(&value) && self.map.insert(Rc::new(value), ()).is_none()
+    }
+
+    /// Prepends `value` to the set.  Returns `false` (leaving the set unchanged) if the set already holds `value`.
+    pub fn insert_front(&mut self, value: T) -> bool {
+        !self.contains(&value) && self.map.insert_front(Rc::new(value), ()).is_none()
+    }
+
+    /// Returns a new set of the values in both this set and `other`, in this set's order.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.filtered(|value| other.contains(value))
+    }
+
+    /// Predicate revealing whether the set holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Creates a double-ended `Iterator` over the set's values, in order.  Use `.rev()` to iterate from the back.
+    pub fn iter(&self) -> Elements<'_, T> {
+        Elements {
+            entries: self.map.iter(),
+        }
+    }
+
+    /// The number of values held by the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Moves `value` to the back of the set.  Returns `false` if the set does not hold `value`.
+    pub fn move_to_back(&mut self, value: &T) -> bool {
+        self.map.move_to_back(value)
+    }
+
+    /// Removes `value` from the set.  Returns `false` if the set did not hold `value`.
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns a new set of the values in this set followed by those values in `other` which are not in this set, each
+    /// in their own set's order.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.filtered(|_| true);
+        // Values already in `union` keep their position
+        other.map
+             .iter()
+             .for_each(|entry| { union.map.insert(Rc::clone(&entry.key()), ()); });
+        union
+    }
+
+    /// Returns a new set of the values in this set which satisfy `predicate`, in this set's order.
+    fn filtered<F>(&self, mut predicate: F) -> Self where F: FnMut(&T) -> bool {
+        let mut set = Self::new();
+        self.map
+            .iter()
+            .filter(|entry| predicate(&entry.key()))
+            .for_each(|entry| { set.map.insert(Rc::clone(&entry.key()), ()); });
+        set
+    }
+}
+
+impl<T: Eq + Hash> Default for LinkedHashSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug + Eq + Hash> Debug for LinkedHashSet<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut set = f.debug_set();
+        self.iter().for_each(|value| { set.entry(&*value.get()); });
+        set.finish()
+    }
+}
+
+/// `Elements` iterates over a `LinkedHashSet`'s values in order, from either end.  It borrows the set, which therefore
+/// cannot be modified during iteration.
+#[derive(Debug)]
+pub struct Elements<'a, T> {
+    entries: Entries<'a, Rc<T>, ()>,
+}
+
+impl<'a, T> Iterator for Elements<'a, T> {
+    type Item = ElementRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| ElementRef { entry })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Elements<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries.next_back().map(|entry| ElementRef { entry })
+    }
+}
+
+impl<T> ExactSizeIterator for Elements<'_, T> {}
+
+/// `ElementRef` refers to one value of a `LinkedHashSet`, as yielded by `Elements`.  It borrows the set, which
+/// therefore cannot be modified while it exists.
+pub struct ElementRef<'a, T> {
+    entry: EntryRef<'a, Rc<T>, ()>,
+}
+
+impl<T> ElementRef<'_, T> {
+    /// Borrows the value.
+    pub fn get(&self) -> Ref<'_, T> {
+        Ref::map(self.entry.key(), |value| &**value)
+    }
+}
+
+impl<T: Debug> Debug for ElementRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&*self.get(), f)
+    }
+}
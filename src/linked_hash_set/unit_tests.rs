@@ -0,0 +1,133 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn set_of(values: &[i32]) -> LinkedHashSet<i32> {
+    let mut set = LinkedHashSet::new();
+    values.iter().for_each(|&value| { set.insert(value); });
+    set
+}
+
+fn contents(set: &LinkedHashSet<i32>) -> Vec<i32> {
+    set.iter().map(|value| *value.get()).collect()
+}
+
+#[test]
+fn insert_preserves_insertion_order_and_ignores_duplicates() {
+    // given an empty set
+    let mut sut = LinkedHashSet::new();
+
+    // when values are inserted, including a duplicate
+    let result = [sut.insert(3), sut.insert(1), sut.insert(3), sut.insert(2)];
+
+    // then only the first insertion of each value succeeds
+    assert_eq!(result, [true, true, false, true]);
+
+    // and values are held in insertion order
+    assert_eq!(contents(&sut), vec![3, 1, 2]);
+    assert_eq!(sut.iter().rev().map(|value| *value.get()).collect::<Vec<_>>(), vec![2, 1, 3]);
+}
+
+#[test]
+fn insert_front_prepends_new_values_only() {
+    // given a set
+    let mut sut = set_of(&[2, 3]);
+
+    // when values are inserted at the front, including one already held
+    let result = (sut.insert_front(1), sut.insert_front(3));
+
+    // then only the new value is prepended
+    assert_eq!(result, (true, false));
+    assert_eq!(contents(&sut), vec![1, 2, 3]);
+}
+
+#[test]
+fn move_to_back_reorders_held_values() {
+    // given a set
+    let mut sut = set_of(&[1, 2, 3]);
+
+    // when a held value and an absent value are moved to the back
+    let result = (sut.move_to_back(&1), sut.move_to_back(&9));
+
+    // then only the held value moves
+    assert_eq!(result, (true, false));
+    assert_eq!(contents(&sut), vec![2, 3, 1]);
+}
+
+#[test]
+fn remove_and_contains_track_membership() {
+    // given a set
+    let mut sut = set_of(&[1, 2, 3]);
+
+    // when a value is removed, twice
+    let result = (sut.remove(&2), sut.remove(&2));
+
+    // then it is removed once, and is no longer held
+    assert_eq!(result, (true, false));
+    assert!(!sut.contains(&2));
+    assert!(sut.contains(&3));
+    assert_eq!(sut.len(), 2);
+}
+
+#[test]
+fn set_operations_preserve_the_left_operands_order() {
+    // given two sets sharing some values in different orders
+    let left = set_of(&[5, 1, 4, 2]);
+    let right = set_of(&[2, 6, 5, 3]);
+
+    // when their union, intersection and difference are taken
+    let result = (contents(&left.union(&right)),
+                  contents(&left.intersection(&right)),
+                  contents(&left.difference(&right)));
+
+    // then each result follows the left operand's order, with the union's additions in the right operand's order
+    assert_eq!(result, (vec![5, 1, 4, 2, 6, 3], vec![5, 2], vec![1, 4]));
+}
+
+#[test]
+fn set_operations_on_an_empty_set() {
+    // given an empty set and a non-empty set
+    let (empty, full) = (LinkedHashSet::default(), set_of(&[1, 2]));
+
+    // when set operations combine them
+    let result = (contents(&empty.union(&full)),
+                  empty.intersection(&full).is_empty(),
+                  contents(&full.difference(&empty)));
+
+    // then the empty set contributes nothing
+    assert_eq!(result, (vec![1, 2], true, vec![1, 2]));
+}
+
+#[test]
+fn debug_formats_values_in_order() {
+    // given a set
+    let sut = set_of(&[3, 1, 2]);
+
+    // when it is formatted
+    let result = format!("{:?}", sut);
+
+    // then its values appear in order
+    assert_eq!(result, "{3, 1, 2}");
+}
+
+/// A value which cannot be cloned.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Unique(i32);
+
+#[test]
+fn values_need_not_be_clone() {
+    // given two sets of values which cannot be cloned
+    let mut lhs = LinkedHashSet::new();
+    let mut rhs = LinkedHashSet::new();
+    (1..4).for_each(|value| { lhs.insert(Unique(value)); });
+    (2..5).for_each(|value| { rhs.insert(Unique(value)); });
+
+    // when set operations are applied to them
+    let values = |set: &LinkedHashSet<Unique>| set.iter().map(|value| value.get().0).collect::<Vec<_>>();
+    let result = (values(&lhs.union(&rhs)), values(&lhs.intersection(&rhs)), values(&lhs.difference(&rhs)));
+
+    // then the resulting sets hold the expected values, in the left operand's order
+    assert_eq!(result, (vec![1, 2, 3, 4], vec![2, 3], vec![1]));
+
+    // and membership is tested by value
+    assert!(lhs.contains(&Unique(1)) && !lhs.contains(&Unique(4)));
+}
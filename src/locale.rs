@@ -0,0 +1,31 @@
+/// Selects the language in which user-facing messages (e.g. `Error` descriptions) are rendered.
+/// `Locale::default()` is `EnUs` unless one of the `locale-*` cargo features selects a different default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    DeDe,
+    EnUs,
+    FrFr,
+    JaJp,
+}
+
+impl Locale {
+    /// Every supported `Locale`.
+    pub const ALL: &'static [Self] = &[Locale::DeDe, Locale::EnUs, Locale::FrFr, Locale::JaJp];
+}
+
+/// The default `Locale` is chosen at compile time via cargo feature; `EnUs` if no `locale-*` feature is enabled.
+/// Features are additive, so if several `locale-*` features are enabled the first of `locale-de-de`, `locale-fr-fr`
+/// and `locale-ja-jp` wins.
+impl Default for Locale {
+    fn default() -> Self {
+        if cfg!(feature = "locale-de-de") {
+            Locale::DeDe
+        } else if cfg!(feature = "locale-fr-fr") {
+            Locale::FrFr
+        } else if cfg!(feature = "locale-ja-jp") {
+            Locale::JaJp
+        } else {
+            Locale::EnUs
+        }
+    }
+}
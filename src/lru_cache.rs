@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    DoublyLinkedList,
+    NodeLink,
+};
+use alloc::boxed::Box;
+use core::{
+    cell::{
+        Ref,
+        RefMut,
+    },
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    hash::Hash,
+};
+use std::collections::HashMap;
+
+/// `LruCache` maps keys to values, holding at most `capacity` entries.  A `HashMap` locates each entry's `Node` in a
+/// `DoublyLinkedList` ordered from most recently used (head) to least recently used (tail), so lookups, promotions
+/// and evictions are all O(1).  When an insertion exceeds the cache's capacity, the least recently used entry is
+/// evicted and passed to the eviction callback, if one is set.  Requires the `std` feature.
+pub struct LruCache<K, V> {
+    map: HashMap<K, NodeLink<(K, V)>>,
+    order: DoublyLinkedList<(K, V)>,
+    capacity: usize,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K: Clone + Eq + Hash, V> LruCache<K, V> {
+    /// Constructor for a cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            order: DoublyLinkedList::new(),
+            capacity,
+            on_evict: None,
+        }
+    }
+
+    /// The maximum number of entries the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Predicate revealing whether the cache holds an entry for `key`.  Does not affect the entry's recency.
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the value for `key`, promoting the entry to most recently used.
+    pub fn get(&mut self, key: &K) -> Option<Ref<'_, V>> {
+        let node = self.promote(key)?;
+        Some(Ref::map(node.borrow(), |node| &node.data.1))
+    }
+
+    /// Returns the value for `key` mutably, promoting the entry to most recently used.
+    pub fn get_mut(&mut self, key: &K) -> Option<RefMut<'_, V>> {
+        let node = self.promote(key)?;
+        Some(RefMut::map(node.borrow_mut(), |node| &mut node.data.1))
+    }
+
+    /// Predicate revealing whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The number of entries held by the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the value for `key` without affecting the entry's recency.
+    pub fn peek(&self, key: &K) -> Option<Ref<'_, V>> {
+        self.map
+            .get(key)
+            .map(|node| Ref::map(node.borrow(), |node| &node.data.1))
+    }
+
+    /// Returns the least recently used entry's key and value without affecting its recency.
+    pub fn peek_lru(&self) -> Option<Ref<'_, (K, V)>> {
+        self.order
+            .tail_link()
+            .and_then(|tail| self.map.get(&tail.borrow().data.0))
+            .map(|node| Ref::map(node.borrow(), |node| &node.data))
+    }
+
+    /// Removes the least recently used entry and returns its key and value.  The eviction callback is not invoked.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let key = self.order.tail_link()?.borrow().data.0.clone();
+        self.remove_entry(&key)
+    }
+
+    /// Inserts `value` for `key` as the most recently used entry, returning the value it replaced, if any.  If the
+    /// insertion leaves the cache over capacity, the least recently used entry is evicted.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        match self.promote(&key) {
+            Some(node) => Some(core::mem::replace(&mut node.borrow_mut().data.1, value)),
+            None => {
+                let head = self.order.push_front_link((key.clone(), value));
+                self.map.insert(key, head);
+                self.evict_excess();
+                None
+            },
+        }
+    }
+
+    /// Removes the entry for `key` and returns its value.  The eviction callback is not invoked.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Changes the cache's capacity to `capacity`, evicting least recently used entries until it fits.
+    pub fn resize(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = capacity;
+        self.evict_excess();
+        self
+    }
+
+    /// Registers `callback` to receive the key and value of every entry subsequently evicted to honor the cache's
+    /// capacity.  Entries removed explicitly (by `pop_lru()` or `remove()`) or replaced by `put()` are not reported.
+    pub fn set_eviction_callback<F>(&mut self, callback: F) -> &mut Self where F: FnMut(K, V) + 'static {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    fn evict_excess(&mut self) {
+        while self.map.len() > self.capacity {
+            match (self.pop_lru(), self.on_evict.as_mut()) {
+                (Some((key, value)), Some(callback)) => callback(key, value),
+                (Some(_), None) => (),
+                (None, _) => break,
+            }
+        }
+    }
+
+    /// Moves the entry for `key` to the front of the recency order, returning its `Node`.
+    fn promote(&mut self, key: &K) -> Option<&NodeLink<(K, V)>> {
+        let node = self.map.get(key)?;
+        self.order
+            .move_to_front(node)
+            .expect(msg::ERR_INTERNAL_NODE_NOT_IN_LIST);
+        Some(node)
+    }
+
+    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        let node = self.map.remove(key)?;
+        Some(self.order
+                 .remove(node)
+                 .expect(msg::ERR_INTERNAL_UNEXPECTED_LIVE_REFERENCES))
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LruCache")
+         .field("order", &self.order)
+         .field("capacity", &self.capacity)
+         .finish()
+    }
+}
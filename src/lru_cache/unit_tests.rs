@@ -0,0 +1,168 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+/// Returns the cache's keys, from most to least recently used.
+fn recency<V>(cache: &LruCache<&'static str, V>) -> Vec<&'static str> {
+    cache.order
+         .iter()
+         .map(|link| link.borrow().0)
+         .collect()
+}
+
+#[test]
+fn put_within_capacity_holds_every_entry_most_recent_first() {
+    // given an empty cache with capacity for three entries
+    let mut sut = LruCache::new(3);
+
+    // when three entries are put
+    let result = (sut.put("a", 1), sut.put("b", 2), sut.put("c", 3));
+
+    // then no values are replaced
+    assert_eq!(result, (None, None, None));
+
+    // and every entry is held, most recently used first
+    assert_eq!(sut.len(), 3);
+    assert_eq!(recency(&sut), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn put_of_an_existing_key_replaces_its_value_and_promotes_it() {
+    // given a cache holding two entries
+    let mut sut = LruCache::new(2);
+    sut.put("a", 1);
+    sut.put("b", 2);
+
+    // when the least recently used key is put again
+    let result = sut.put("a", 10);
+
+    // then the previous value is returned
+    assert_eq!(result, Some(1));
+
+    // and the entry is updated and most recently used
+    assert_eq!(sut.peek(&"a").map(|value| *value), Some(10));
+    assert_eq!(recency(&sut), vec!["a", "b"]);
+}
+
+#[test]
+fn put_beyond_capacity_evicts_the_least_recently_used_entry() {
+    // given a full cache whose oldest entry has been read since it was put
+    let mut sut = LruCache::new(2);
+    sut.put("a", 1);
+    sut.put("b", 2);
+    assert_eq!(sut.get(&"a").map(|value| *value), Some(1));
+
+    // when another entry is put
+    sut.put("c", 3);
+
+    // then the least recently used entry is evicted
+    assert!(!sut.contains(&"b"));
+    assert_eq!(recency(&sut), vec!["c", "a"]);
+}
+
+#[test]
+fn peek_does_not_promote() {
+    // given a full cache
+    let mut sut = LruCache::new(2);
+    sut.put("a", 1);
+    sut.put("b", 2);
+
+    // when its least recently used entry is peeked at
+    let result = sut.peek(&"a").map(|value| *value);
+
+    // then the value is returned
+    assert_eq!(result, Some(1));
+
+    // and the entry remains least recently used, so is evicted next
+    assert_eq!(sut.peek_lru().map(|entry| *entry), Some(("a", 1)));
+    sut.put("c", 3);
+    assert_eq!(recency(&sut), vec!["c", "b"]);
+}
+
+#[test]
+fn get_mut_updates_the_value_in_place() {
+    // given a cache holding two entries
+    let mut sut = LruCache::new(2);
+    sut.put("a", 1);
+    sut.put("b", 2);
+
+    // when a value is updated through `get_mut()`
+    *sut.get_mut(&"a").unwrap() += 10;
+
+    // then the value is updated, and the entry promoted
+    assert_eq!(sut.peek(&"a").map(|value| *value), Some(11));
+    assert_eq!(recency(&sut), vec!["a", "b"]);
+}
+
+#[test]
+fn get_of_an_absent_key_returns_none() {
+    // given a cache holding an entry
+    let mut sut = LruCache::new(1);
+    sut.put("a", 1);
+
+    // when an absent key is read
+    let result = sut.get(&"z").is_none();
+
+    // then nothing is found
+    assert!(result);
+}
+
+#[test]
+fn pop_lru_and_remove_return_entries_without_invoking_the_eviction_callback() {
+    // given a cache with an eviction callback
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = LruCache::new(3);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    sut.put("a", 1);
+    sut.put("b", 2);
+    sut.put("c", 3);
+
+    // when entries are removed explicitly
+    let result = (sut.pop_lru(), sut.remove(&"c"), sut.remove(&"c"));
+
+    // then the entries are returned
+    assert_eq!(result, (Some(("a", 1)), Some(3), None));
+    assert_eq!(recency(&sut), vec!["b"]);
+
+    // and the callback was not invoked
+    assert!(evicted.borrow().is_empty());
+}
+
+#[test]
+fn resize_evicts_least_recently_used_entries_to_the_eviction_callback() {
+    // given a full cache with an eviction callback
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut sut = LruCache::new(4);
+    sut.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    ["a", "b", "c", "d"].iter().zip(1..).for_each(|(&key, value)| { sut.put(key, value); });
+
+    // when the cache is shrunk
+    sut.resize(1);
+
+    // then the least recently used entries are evicted, oldest first
+    assert_eq!(*evicted.borrow(), vec![("a", 1), ("b", 2), ("c", 3)]);
+    assert_eq!(sut.capacity(), 1);
+    assert_eq!(recency(&sut), vec!["d"]);
+
+    // and subsequent evictions are reported too
+    sut.put("e", 5);
+    assert_eq!(evicted.borrow().last(), Some(&("d", 4)));
+}
+
+#[test]
+fn zero_capacity_cache_evicts_every_entry() {
+    // given a cache with no capacity
+    let mut sut = LruCache::new(0);
+
+    // when an entry is put
+    sut.put("a", 1);
+
+    // then it is evicted immediately
+    assert!(sut.is_empty());
+    assert_eq!(sut.pop_lru(), None);
+}
@@ -6,7 +6,8 @@ pub use self::{
     node_link::NodeLink,
     weak_link::WeakLink,
 };
-use std::{
+use alloc::rc::Weak;
+use core::{
     cmp::Ordering,
     ops::Deref,
 };
@@ -15,7 +16,10 @@ use std::{
 pub struct Node<T> {
     pub(super) next: Option<NodeLink<T>>,
     pub(super) prev: Option<WeakLink<T>>,
+    // Set only on the tail of a `CircularList`, where it refers back to the head to close the ring
+    pub(super) wrap: Option<WeakLink<T>>,
     pub(super) data: T,
+    pub(super) owner: Weak<()>,
 }
 
 impl<T> Node<T> {
@@ -23,9 +27,19 @@ impl<T> Node<T> {
         Self {
             next: None,
             prev: None,
+            wrap: None,
             data,
+            owner: Weak::new(),
         }
     }
+
+    /// Severs this `Node`'s links to its neighbours and to the list which owned it.
+    pub(crate) fn detach(&mut self) {
+        self.next = None;
+        self.prev = None;
+        self.wrap = None;
+        self.owner = Weak::new();
+    }
 }
 
 impl<T> Deref for Node<T> {
@@ -38,7 +52,7 @@ impl<T> Deref for Node<T> {
 
 impl<T> PartialEq for Node<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        self as *const Node<T> == rhs as *const Node<T>
+        core::ptr::eq(self, rhs)
     }
 }
 
@@ -1,7 +1,7 @@
-use std::{
+use alloc::rc::Rc;
+use core::{
     cell::RefCell,
     ops::Deref,
-    rc::Rc,
 };
 use crate::{
     Node,
@@ -43,7 +43,7 @@ impl<T> Deref for NodeLink<T> {
 
 impl<T: PartialEq> PartialEq for NodeLink<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        self == rhs
+        Rc::ptr_eq(&self.0, &rhs.0)
     }
 }
 
@@ -18,4 +18,18 @@ fn new_node_has_expected_state() {
 
     // and the `prev` link should be `None`
     assert_eq!(result.prev, None);
-}
\ No newline at end of file
+}
+
+#[test]
+fn links_compare_by_identity_rather_than_by_data() {
+    // given two links to one `Node`, and a link to another `Node` holding equal data
+    let link = NodeLink::new(Node::new(String::from("sample node data")));
+    let same = link.clone();
+    let other = NodeLink::new(Node::new(String::from("sample node data")));
+
+    // when the links, and weak links derived from them, are compared
+    let result = (link == same, link == other, link.to_weak() == same.to_weak(), link.to_weak() == other.to_weak());
+
+    // then only links to the same `Node` compare equal
+    assert_eq!(result, (true, false, true, false));
+}
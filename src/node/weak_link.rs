@@ -1,7 +1,5 @@
-use std::{
-    cell::RefCell,
-    rc::Weak,
-};
+use alloc::rc::Weak;
+use core::cell::RefCell;
 use crate::{
     Node,
     NodeLink,
@@ -16,9 +14,15 @@ impl<T> WeakLink<T> {
         Self(weak_link)
     }
 
+    /// The address of the referenced `Node`, which identifies it for as long as this `WeakLink` exists.
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const RefCell<Node<T>> {
+        Weak::as_ptr(&self.0)
+    }
+
     #[inline]
     pub(crate) fn to_strong(&self) -> Option<NodeLink<T>> {
-        Weak::upgrade(&self.0).and_then(|link| Some(NodeLink::from_strong(link)))
+        Weak::upgrade(&self.0).map(NodeLink::from_strong)
     }
 }
 
@@ -30,7 +34,7 @@ impl<T> Clone for WeakLink<T> {
 
 impl<T: PartialEq> PartialEq for WeakLink<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        self == rhs
+        Weak::ptr_eq(&self.0, &rhs.0)
     }
 }
 
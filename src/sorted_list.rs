@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    DoublyLinkedList,
+    Error,
+    NodeLink,
+    Operation,
+    Result,
+    SearchFrom,
+};
+use core::ops::Deref;
+
+/// `SortedList` wraps a `DoublyLinkedList`, keeping its elements in ascending order as they are inserted, and so can
+/// serve as a priority queue: the minimum element is at the head and the maximum at the tail.  Equal elements retain
+/// their insertion order.
+#[derive(Debug)]
+pub struct SortedList<T: Ord> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T: Ord> SortedList<T> {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Inserts `data` in order, searching for its position from the tail (suited to mostly-ascending insertions).
+    /// Returns a `NodeLink` to the inserted `Node`, which may later be passed to `remove()`; note that while it is
+    /// held, the `Node` cannot be popped.
+    pub fn insert(&mut self, data: T) -> NodeLink<T> {
+        self.insert_from(data, SearchFrom::Back)
+    }
+
+    /// Inserts `data` in order, searching for its position from the end of the list specified by `hint`.  Returns a
+    /// `NodeLink` to the inserted `Node`.
+    pub fn insert_from(&mut self, data: T, hint: SearchFrom) -> NodeLink<T> {
+        self.list.insert_sorted(data, hint)
+    }
+
+    /// Removes the greatest element (the last inserted, among equals) and returns it.  If a `NodeLink` to that element
+    /// is still held, returns `Error::ExistingLiveReferences`, leaving the list unchanged.
+    pub fn pop_max(&mut self) -> Result<T> {
+        match self.list.tail_link() {
+            Some(tail) => self.list.remove(tail).map_err(|err| err.in_op(Operation::PopBack)),
+            None => Err(Error::EmptyList { op: Operation::PopBack }),
+        }
+    }
+
+    /// Removes the least element (the first inserted, among equals) and returns it.  If a `NodeLink` to that element
+    /// is still held, returns `Error::ExistingLiveReferences`, leaving the list unchanged.
+    pub fn pop_min(&mut self) -> Result<T> {
+        match self.list.head_link() {
+            Some(head) => self.list.remove(head).map_err(|err| err.in_op(Operation::PopFront)),
+            None => Err(Error::EmptyList { op: Operation::PopFront }),
+        }
+    }
+
+    /// Removes the element referenced by `node` and returns it.  To change an element's priority, `remove()` it and
+    /// `insert()` the updated value.
+    pub fn remove(&mut self, node: NodeLink<T>) -> Result<T> {
+        self.list.remove(node)
+    }
+}
+
+/// Idiomatic `Default` impl for types with parameterless constructors.
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read-only access to the underlying `DoublyLinkedList`.  Mutable access is not offered, as it would permit breaking
+/// the ordering invariant.
+impl<T: Ord> Deref for SortedList<T> {
+    type Target = DoublyLinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+/// Orders by `key` only, so that elements with equal keys can be told apart by `tag`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Keyed {
+    key: u32,
+    tag: char,
+}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&rhs.key)
+    }
+}
+
+fn keyed(key: u32, tag: char) -> Keyed {
+    Keyed { key, tag }
+}
+
+fn contents<T: Ord + Clone>(list: &SortedList<T>) -> Vec<T> {
+    list.iter()
+        .map(|link| link.borrow().data.clone())
+        .collect()
+}
+
+#[test]
+fn insert_keeps_elements_in_ascending_order() {
+    // given an empty sorted list
+    let mut sut = SortedList::new();
+
+    // when elements are inserted out of order
+    [5, 1, 4, 2, 3].iter().for_each(|&datum| { sut.insert(datum); });
+
+    // then the elements are held in ascending order
+    assert_eq!(contents(&sut), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn insert_from_either_end_yields_the_same_order() {
+    // given two empty sorted lists
+    let mut from_front = SortedList::new();
+    let mut from_back = SortedList::new();
+
+    // when the same elements are inserted, searching from opposite ends
+    for &datum in &[3, 9, 1, 7, 5, 5, 0] {
+        from_front.insert_from(datum, SearchFrom::Front);
+        from_back.insert_from(datum, SearchFrom::Back);
+    }
+
+    // then both lists hold the elements in ascending order
+    assert_eq!(contents(&from_front), vec![0, 1, 3, 5, 5, 7, 9]);
+    assert_eq!(contents(&from_back), contents(&from_front));
+}
+
+#[test]
+fn insert_of_equal_keys_preserves_insertion_order_from_either_end() {
+    // given two empty sorted lists
+    let mut from_front = SortedList::new();
+    let mut from_back = SortedList::new();
+
+    // when elements with equal keys are inserted, searching from opposite ends
+    for datum in [keyed(2, 'a'), keyed(1, 'b'), keyed(2, 'c'), keyed(1, 'd'), keyed(2, 'e')] {
+        from_front.insert_from(datum.clone(), SearchFrom::Front);
+        from_back.insert_from(datum, SearchFrom::Back);
+    }
+
+    // then equal keys retain their insertion order
+    let expected = vec![keyed(1, 'b'), keyed(1, 'd'), keyed(2, 'a'), keyed(2, 'c'), keyed(2, 'e')];
+    assert_eq!(contents(&from_front).iter().map(|k| k.tag).collect::<String>(), "bdace");
+    assert_eq!(contents(&from_back).iter().map(|k| k.tag).collect::<String>(), "bdace");
+    assert_eq!(contents(&from_front), expected);
+}
+
+#[test]
+fn pop_min_and_pop_max_yield_elements_from_either_end() {
+    // given a sorted list
+    let mut sut = SortedList::new();
+    [2, 3, 1].iter().for_each(|&datum| { sut.insert(datum); });
+
+    // when the minimum and maximum are popped
+    let result = (sut.pop_min(), sut.pop_max());
+
+    // then the least and greatest elements are returned
+    assert_eq!(result, (Ok(1), Ok(3)));
+    assert_eq!(contents(&sut), vec![2]);
+}
+
+#[test]
+fn remove_and_reinsert_changes_an_elements_priority() {
+    // given a sorted list and a handle to one of its elements
+    let mut sut = SortedList::new();
+    sut.insert(1);
+    let handle = sut.insert(5);
+    sut.insert(9);
+
+    // when the element is removed and reinserted with a new priority
+    let removed = sut.remove(handle);
+    sut.insert(10);
+
+    // then the old value is returned
+    assert_eq!(removed, Ok(5));
+
+    // and the list reflects the new priority
+    assert_eq!(contents(&sut), vec![1, 9, 10]);
+    assert_eq!(sut.pop_max(), Ok(10));
+}
+
+#[test]
+fn pop_min_of_a_held_element_leaves_the_list_unchanged() {
+    // given a sorted list and a handle to its least element
+    let mut sut = SortedList::new();
+    let handle = sut.insert(1);
+    sut.insert(2);
+
+    // when the minimum is popped
+    let result = sut.pop_min();
+
+    // then extraction fails
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::PopFront, .. })));
+
+    // and the element remains in the list, removable by its handle
+    assert_eq!(contents(&sut), vec![1, 2]);
+    assert_eq!(sut.remove(handle), Ok(1));
+}
+
+#[test]
+fn pop_max_of_a_held_element_leaves_the_list_unchanged() {
+    // given a sorted list and a handle to its greatest element
+    let mut sut = SortedList::new();
+    sut.insert(1);
+    let handle = sut.insert(2);
+
+    // when the maximum is popped
+    let result = sut.pop_max();
+
+    // then extraction fails
+    assert!(matches!(result, Err(Error::ExistingLiveReferences { op: Operation::PopBack, .. })));
+
+    // and the element remains in the list, removable by its handle
+    assert_eq!(contents(&sut), vec![1, 2]);
+    assert_eq!(sut.remove(handle), Ok(2));
+}
@@ -0,0 +1,288 @@
+#[cfg(test)]
+mod unit_tests;
+use crate::{
+    consts::msg,
+    DoublyLinkedList,
+    WeakLink,
+};
+use alloc::{
+    rc::{
+        Rc,
+        Weak,
+    },
+    vec::Vec,
+};
+use core::{
+    cell::RefCell,
+    convert::TryFrom,
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+};
+
+/// The number of bits of a deadline which index the slots of each level.
+const SLOT_BITS: u32 = 6;
+/// The number of slots in each level.
+const SLOTS: usize = 64;
+const SLOT_MASK: u64 = 63;
+/// The number of levels needed for every `u64` deadline to share its remaining high bits with the current time.
+const MAX_LEVELS: usize = 11;
+
+/// Where a pending timer is held: the slot, and the `Node` within it.
+struct Location<T> {
+    level: usize,
+    slot: usize,
+    node: WeakLink<Timer<T>>,
+}
+
+/// A pending timer.  Its `Location` is shared (weakly) with its `TimerHandle`s, and updated as it cascades between
+/// levels.
+struct Timer<T> {
+    deadline: u64,
+    data: T,
+    location: Rc<RefCell<Location<T>>>,
+}
+
+/// Identifies a timer scheduled with `TimingWheel::schedule()`, for use with `cancel()`.  A handle does not keep its
+/// timer alive: once the timer expires or is cancelled, the handle is inert.
+pub struct TimerHandle<T> {
+    location: Weak<RefCell<Location<T>>>,
+}
+
+impl<T> TimerHandle<T> {
+    /// The tick at which the timer expires, or `None` if it has expired or been cancelled.
+    pub fn deadline(&self) -> Option<u64> {
+        let location = self.location.upgrade()?;
+        let node = location.borrow().node.to_strong()?;
+        let deadline = node.borrow().data.deadline;
+        Some(deadline)
+    }
+
+    /// Predicate revealing whether the timer has neither expired nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        self.location.strong_count() > 0
+    }
+}
+
+impl<T> Clone for TimerHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            location: Weak::clone(&self.location),
+        }
+    }
+}
+
+impl<T> Debug for TimerHandle<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("TimerHandle")
+         .field("deadline", &self.deadline())
+         .finish()
+    }
+}
+
+/// `TimingWheel` schedules data to expire after a delay, measured in ticks of an explicit clock which is advanced by
+/// the caller (e.g. from `std::time::Instant`, or from a test).  Timers are held in hierarchical wheels of 64 slots
+/// each, every slot a `DoublyLinkedList`: the first level holds timers due within the next 64 ticks, one slot per tick,
+/// and each further level holds timers 64 times further out, at 64 times coarser granularity.  As time advances, the
+/// timers of a coarser slot cascade into finer ones, so scheduling and cancellation are O(1), and each timer cascades
+/// at most once per level.  Levels are allocated as the delays scheduled require them.
+pub struct TimingWheel<T> {
+    levels: Vec<Vec<DoublyLinkedList<Timer<T>>>>,
+    now: u64,
+    len: usize,
+}
+
+impl<T> TimingWheel<T> {
+    /// Constructor for an empty wheel whose clock reads 0.
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Constructor for an empty wheel whose clock reads `now`.
+    pub fn starting_at(now: u64) -> Self {
+        Self {
+            levels: Vec::new(),
+            now,
+            len: 0,
+        }
+    }
+
+    /// Advances the clock by `ticks`, returning the data of every timer thereby expired, in order of deadline.
+    pub fn advance(&mut self, ticks: u64) -> Vec<T> {
+        self.advance_to(self.now.saturating_add(ticks))
+    }
+
+    /// Advances the clock to `instant`, returning the data of every timer thereby expired, in order of deadline.  Does
+    /// nothing if the clock already reads `instant` or later.  The clock jumps directly to each tick at which a slot
+    /// of some level falls due (expiring or cascading its timers), so the cost depends on the number of timers and
+    /// occupied slots passed, not on the number of ticks.
+    pub fn advance_to(&mut self, instant: u64) -> Vec<T> {
+        let mut expired = Vec::new();
+        while self.now < instant {
+            match self.next_due().filter(|&due| due <= instant) {
+                Some(due) => {
+                    self.now = due;
+                    self.tick(&mut expired);
+                },
+                None => self.now = instant,
+            }
+        }
+        expired
+    }
+
+    /// Cancels the timer identified by `handle`, returning its data.  Returns `None` if the timer has expired, has
+    /// already been cancelled, or was not scheduled on this wheel.
+    pub fn cancel(&mut self, handle: TimerHandle<T>) -> Option<T> {
+        let location = handle.location.upgrade()?;
+        let (level, slot, node) = {
+            let location = location.borrow();
+            (location.level, location.slot, location.node.to_strong()?)
+        };
+        let timer = self.levels
+                        .get_mut(level)?
+                        .get_mut(slot)?
+                        .remove(node)
+                        .ok()?;
+        self.len = self.len.saturating_sub(1);
+        Some(timer.data)
+    }
+
+    /// Predicate revealing whether no timers are pending.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of pending timers.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The tick the clock currently reads.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Schedules `data` to expire `delay` ticks from now, returning a handle with which to cancel it.  A `delay` of 0
+    /// is treated as 1, so the timer expires on the next tick.  Deadlines beyond `u64::MAX` are clamped to it.
+    pub fn schedule(&mut self, delay: u64, data: T) -> TimerHandle<T> {
+        let location = Rc::new(RefCell::new(Location {
+                                                level: 0,
+                                                slot: 0,
+                                                node: WeakLink::from_weak(Weak::new()),
+                                            }));
+        let handle = TimerHandle {
+            location: Rc::downgrade(&location),
+        };
+        self.place(Timer {
+                       deadline: self.now.saturating_add(delay.max(1)),
+                       data,
+                       location,
+                   });
+        self.len = self.len.saturating_add(1);
+        handle
+    }
+
+    /// The level in which a timer due at `deadline` is held: the finest whose coarser levels' indices of `deadline`
+    /// and `now` coincide.
+    fn level_of(now: u64, deadline: u64) -> usize {
+        (0..MAX_LEVELS).find(|&level| {
+                           let shift = Self::shift(level.saturating_add(1));
+                           deadline.checked_shr(shift).unwrap_or(0) == now.checked_shr(shift).unwrap_or(0)
+                       })
+                       .unwrap_or(MAX_LEVELS.saturating_sub(1))
+    }
+
+    /// The earliest tick after the clock at which an occupied slot falls due: the start of the next occupied slot of
+    /// any level.  Slots at or before a level's current slot are never occupied, so only those after it are searched.
+    fn next_due(&self) -> Option<u64> {
+        self.levels
+            .iter()
+            .enumerate()
+            .filter_map(|(level, slots)| {
+                // The first tick of the span of `level`'s slots containing the clock
+                let span_shift = Self::shift(level.saturating_add(1));
+                let base = self.now
+                               .checked_shr(span_shift)
+                               .and_then(|high| high.checked_shl(span_shift))
+                               .unwrap_or(0);
+                slots.iter()
+                     .enumerate()
+                     .skip(Self::slot_of(self.now, level).saturating_add(1))
+                     .find(|(_, list)| list.head_link().is_some())
+                     .and_then(|(slot, _)| u64::try_from(slot).ok())
+                     .and_then(|slot| slot.checked_shl(Self::shift(level)))
+                     .map(|start| base | start)
+            })
+            .min()
+    }
+
+    /// Inserts `timer` into the slot for its deadline, relative to the clock, recording its new `Location`.
+    fn place(&mut self, timer: Timer<T>) {
+        let level = Self::level_of(self.now, timer.deadline);
+        let slot = Self::slot_of(timer.deadline, level);
+        while self.levels.len() <= level {
+            self.levels.push((0..SLOTS).map(|_| DoublyLinkedList::new()).collect());
+        }
+        let location = Rc::clone(&timer.location);
+        let list = self.levels
+                       .get_mut(level)
+                       .and_then(|slots| slots.get_mut(slot))
+                       .expect(msg::ERR_INTERNAL_INVALID_INDEX);
+        let node = list.push_back_link(timer);
+        *location.borrow_mut() = Location { level, slot, node: node.to_weak() };
+    }
+
+    /// The bit offset of the index of `level`'s slots within a deadline.
+    fn shift(level: usize) -> u32 {
+        u32::try_from(level).unwrap_or(u32::MAX).saturating_mul(SLOT_BITS)
+    }
+
+    /// The index of the slot of `level` holding timers due at `instant`.
+    fn slot_of(instant: u64, level: usize) -> usize {
+        usize::try_from(instant.checked_shr(Self::shift(level)).unwrap_or(0) & SLOT_MASK).unwrap_or_default()
+    }
+
+    /// Processes the tick the clock has just reached: cascades the timers of each coarser slot whose span begins now
+    /// into finer slots (coarsest first), then expires the timers of the finest level's current slot.
+    fn tick(&mut self, expired: &mut Vec<T>) {
+        let now = self.now;
+        for level in (1..self.levels.len()).rev() {
+            if now.trailing_zeros() >= Self::shift(level) {
+                while let Some(timer) = self.take(level, Self::slot_of(now, level)) {
+                    self.place(timer);
+                }
+            }
+        }
+        while let Some(timer) = self.take(0, Self::slot_of(now, 0)) {
+            self.len = self.len.saturating_sub(1);
+            expired.push(timer.data);
+        }
+    }
+
+    /// Removes the first timer from the given slot, if any.
+    fn take(&mut self, level: usize, slot: usize) -> Option<Timer<T>> {
+        self.levels
+            .get_mut(level)?
+            .get_mut(slot)?
+            .pop_front()
+            .ok()
+    }
+}
+
+impl<T> Default for TimingWheel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for TimingWheel<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("TimingWheel")
+         .field("now", &self.now)
+         .field("len", &self.len)
+         .field("levels", &self.levels.len())
+         .finish()
+    }
+}
@@ -0,0 +1,189 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn timers_expire_once_their_delay_has_elapsed() {
+    // given a wheel with timers scheduled at various short delays
+    let mut sut = TimingWheel::new();
+    sut.schedule(3, "c");
+    sut.schedule(1, "a");
+    sut.schedule(2, "b");
+
+    // when the clock is advanced tick by tick
+    let result = (0..4).map(|_| sut.advance(1)).collect::<Vec<_>>();
+
+    // then each timer expires on its deadline
+    assert_eq!(result, vec![vec!["a"], vec!["b"], vec!["c"], vec![]]);
+    assert!(sut.is_empty());
+    assert_eq!(sut.now(), 4);
+}
+
+#[test]
+fn advance_returns_expired_timers_in_deadline_order() {
+    // given a wheel with timers in several levels
+    let mut sut = TimingWheel::new();
+    [5000_u64, 70, 3, 64, 300_000, 63].iter().for_each(|&delay| { sut.schedule(delay, delay); });
+
+    // when the clock is advanced past every deadline at once
+    let result = sut.advance(300_000);
+
+    // then every timer expires, in order of deadline
+    assert_eq!(result, vec![3, 63, 64, 70, 5000, 300_000]);
+    assert!(sut.is_empty());
+}
+
+#[test]
+fn long_delays_cascade_to_expire_on_the_exact_tick() {
+    // given a wheel, not starting at 0, with a timer too distant for the first level
+    let mut sut = TimingWheel::starting_at(1_000);
+    let handle = sut.schedule(4_100, ());
+
+    // when the clock is advanced to one tick before the deadline
+    let early = sut.advance_to(5_099);
+
+    // then the timer has not expired, and its handle still locates it
+    assert!(early.is_empty());
+    assert_eq!(handle.deadline(), Some(5_100));
+
+    // and it expires on the next tick
+    assert_eq!(sut.advance(1).len(), 1);
+    assert!(!handle.is_pending());
+}
+
+#[test]
+fn advance_over_distant_deadlines_jumps_between_occupied_slots() {
+    // given a wheel, not starting at 0, with timers scheduled far apart
+    let mut sut = TimingWheel::starting_at(77);
+    let near = sut.schedule(1 << 28, "near");
+    let far = sut.schedule((1 << 50) + 12_345, "far");
+
+    // when the clock is advanced to one tick before each deadline, then onto it
+    let result = vec![sut.advance_to(76 + (1 << 28)),
+                      sut.advance(1),
+                      sut.advance_to(76 + (1 << 50) + 12_345),
+                      sut.advance(1)];
+
+    // then each timer expires exactly on its deadline
+    assert_eq!(result, vec![vec![], vec!["near"], vec![], vec!["far"]]);
+    assert!(!near.is_pending() && !far.is_pending());
+    assert_eq!(sut.now(), 77 + (1 << 50) + 12_345);
+}
+
+#[test]
+fn cancel_removes_a_pending_timer() {
+    // given a wheel with two timers
+    let mut sut = TimingWheel::new();
+    let first = sut.schedule(10, "first");
+    sut.schedule(10, "second");
+
+    // when one is cancelled, twice
+    let result = (sut.cancel(first.clone()), sut.cancel(first));
+
+    // then it is cancelled once
+    assert_eq!(result, (Some("first"), None));
+    assert_eq!(sut.len(), 1);
+
+    // and only the other timer expires
+    assert_eq!(sut.advance(10), vec!["second"]);
+}
+
+#[test]
+fn cancel_finds_timers_which_have_cascaded() {
+    // given a wheel with a distant timer which has cascaded into a finer level
+    let mut sut = TimingWheel::new();
+    let handle = sut.schedule(10_000, "distant");
+    sut.advance(9_990);
+
+    // when it is cancelled
+    let result = sut.cancel(handle);
+
+    // then it is removed, and does not expire
+    assert_eq!(result, Some("distant"));
+    assert!(sut.advance(100).is_empty());
+}
+
+#[test]
+fn cancel_after_expiry_or_on_another_wheel_does_nothing() {
+    // given two wheels, one with an expired timer, and one with a pending timer
+    let mut sut = TimingWheel::new();
+    let expired = sut.schedule(1, 1);
+    sut.advance(1);
+    let mut other = TimingWheel::new();
+    let foreign = other.schedule(5, 2);
+
+    // when the timers are cancelled on the first wheel
+    let result = (sut.cancel(expired), sut.cancel(foreign.clone()));
+
+    // then nothing is cancelled
+    assert_eq!(result, (None, None));
+
+    // and the other wheel's timer remains pending
+    assert!(foreign.is_pending());
+    assert_eq!(other.advance(5), vec![2]);
+}
+
+#[test]
+fn zero_delay_expires_on_the_next_tick() {
+    // given a wheel with a timer scheduled with no delay
+    let mut sut = TimingWheel::starting_at(7);
+    let handle = sut.schedule(0, "now");
+
+    // when the clock is advanced by a tick
+    let result = (handle.deadline(), sut.advance(1));
+
+    // then the timer expires
+    assert_eq!(result, (Some(8), vec!["now"]));
+}
+
+#[test]
+fn an_empty_wheel_jumps_straight_to_the_instant() {
+    // given an empty wheel
+    let mut sut = TimingWheel::<&str>::default();
+
+    // when its clock is advanced a great distance, then set back
+    let result = (sut.advance_to(u64::MAX - 1), sut.advance_to(5));
+
+    // then nothing expires, and the clock does not run backward
+    assert_eq!(result, (vec![], vec![]));
+    assert_eq!(sut.now(), u64::MAX - 1);
+
+    // and timers may still be scheduled near the end of time
+    sut.schedule(u64::MAX, "clamped");
+    assert_eq!(sut.advance(1), vec!["clamped"]);
+}
+
+#[test]
+fn every_timer_expires_on_its_deadline() {
+    // given a wheel with many timers at pseudo-random delays, and an interleaved cancellation of every fifth one
+    let mut sut = TimingWheel::starting_at(123);
+    let mut seed = 0x2545_f491_u64;
+    let mut delays = (0..500).map(|_| {
+                                 seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                                 (seed >> 33) % 20_000
+                             })
+                             .collect::<Vec<_>>();
+    let handles = delays.iter().map(|&delay| sut.schedule(delay, delay.max(1))).collect::<Vec<_>>();
+    sut.advance(50);
+    let cancelled = handles.into_iter()
+                           .step_by(5)
+                           .filter_map(|handle| sut.cancel(handle))
+                           .collect::<Vec<_>>();
+    delays = delays.into_iter()
+                   .map(|delay| delay.max(1))
+                   .enumerate()
+                   .filter(|(index, delay)| index % 5 != 0 && *delay > 50)
+                   .map(|(_, delay)| delay)
+                   .collect();
+    delays.sort_unstable();
+
+    // when the clock is advanced one tick at a time
+    let mut expired = Vec::new();
+    while !sut.is_empty() {
+        let tick = sut.now() - 123 + 1;
+        expired.extend(sut.advance(1).into_iter().inspect(|&delay| assert_eq!(delay, tick)));
+    }
+
+    // then every uncancelled timer expired exactly on its deadline
+    assert!(cancelled.iter().all(|&delay| delay > 50));
+    assert_eq!(expired, delays);
+}